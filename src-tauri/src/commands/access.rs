@@ -0,0 +1,442 @@
+use crate::s3::types::{
+    is_unsupported_error, AclInfo, GrantInfo, PublicAccessBlockSettings, S3Config,
+};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::types::{
+    AccessControlPolicy, BucketCannedAcl, Grant, Grantee, ObjectCannedAcl, Owner, Permission,
+    PublicAccessBlockConfiguration, Type,
+};
+
+// 代表公共访问的预定义组
+const ALL_USERS_URI: &str = "http://acs.amazonaws.com/groups/global/AllUsers";
+const AUTHENTICATED_USERS_URI: &str = "http://acs.amazonaws.com/groups/global/AuthenticatedUsers";
+
+/// 检查授权列表中是否包含公共访问
+fn grants_are_public(grants: &[Grant]) -> bool {
+    grants.iter().any(|g| {
+        g.grantee()
+            .and_then(|grantee| grantee.uri())
+            .is_some_and(|uri| uri == ALL_USERS_URI || uri == AUTHENTICATED_USERS_URI)
+    })
+}
+
+fn to_acl_info(owner: Option<&Owner>, grants: &[Grant]) -> AclInfo {
+    AclInfo {
+        owner_id: owner.and_then(|o| o.id()).map(|s| s.to_string()),
+        owner_display_name: owner.and_then(|o| o.display_name()).map(|s| s.to_string()),
+        grants: grants
+            .iter()
+            .filter_map(|g| {
+                let grantee = g.grantee()?;
+                Some(GrantInfo {
+                    grantee_type: grantee.r#type().as_str().to_string(),
+                    grantee_id: grantee.id().map(|s| s.to_string()),
+                    grantee_uri: grantee.uri().map(|s| s.to_string()),
+                    grantee_email: grantee.email_address().map(|s| s.to_string()),
+                    grantee_display_name: grantee.display_name().map(|s| s.to_string()),
+                    permission: g.permission()?.as_str().to_string(),
+                })
+            })
+            .collect(),
+        is_public: grants_are_public(grants),
+    }
+}
+
+/// 将 ACL 信息转换为 SDK 的 AccessControlPolicy
+fn build_access_control_policy(acl: AclInfo) -> Result<AccessControlPolicy, String> {
+    let mut grants = Vec::with_capacity(acl.grants.len());
+    for grant in acl.grants {
+        if !Type::values().contains(&grant.grantee_type.as_str()) {
+            return Err(format!("Unsupported grantee type: {}", grant.grantee_type));
+        }
+        if !Permission::values().contains(&grant.permission.as_str()) {
+            return Err(format!("Unsupported permission: {}", grant.permission));
+        }
+
+        let grantee = Grantee::builder()
+            .r#type(Type::from(grant.grantee_type.as_str()))
+            .set_id(grant.grantee_id)
+            .set_uri(grant.grantee_uri)
+            .set_email_address(grant.grantee_email)
+            .set_display_name(grant.grantee_display_name)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        grants.push(
+            Grant::builder()
+                .grantee(grantee)
+                .permission(Permission::from(grant.permission.as_str()))
+                .build(),
+        );
+    }
+
+    let owner = Owner::builder()
+        .set_id(acl.owner_id)
+        .set_display_name(acl.owner_display_name)
+        .build();
+
+    Ok(AccessControlPolicy::builder()
+        .set_grants(Some(grants))
+        .owner(owner)
+        .build())
+}
+
+/// 将公共访问阻止设置转换为 SDK 类型
+pub(crate) fn build_public_access_block(
+    settings: &PublicAccessBlockSettings,
+) -> PublicAccessBlockConfiguration {
+    PublicAccessBlockConfiguration::builder()
+        .block_public_acls(settings.block_public_acls)
+        .ignore_public_acls(settings.ignore_public_acls)
+        .block_public_policy(settings.block_public_policy)
+        .restrict_public_buckets(settings.restrict_public_buckets)
+        .build()
+}
+
+/// 解析 Bucket 预定义 ACL
+pub(crate) fn parse_bucket_canned_acl(acl: &str) -> Result<BucketCannedAcl, String> {
+    if BucketCannedAcl::values().contains(&acl) {
+        Ok(BucketCannedAcl::from(acl))
+    } else {
+        Err(format!("Unsupported canned ACL: {}", acl))
+    }
+}
+
+/// 解析 Object 预定义 ACL
+pub(crate) fn parse_object_canned_acl(acl: &str) -> Result<ObjectCannedAcl, String> {
+    if ObjectCannedAcl::values().contains(&acl) {
+        Ok(ObjectCannedAcl::from(acl))
+    } else {
+        Err(format!("Unsupported canned ACL: {}", acl))
+    }
+}
+
+/// 读取公共访问阻止设置，未配置时所有选项均为 false
+pub(crate) async fn load_public_access_block(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<PublicAccessBlockSettings, String> {
+    match client.get_public_access_block().bucket(bucket).send().await {
+        Ok(result) => Ok(result
+            .public_access_block_configuration()
+            .map(|c| PublicAccessBlockSettings {
+                block_public_acls: c.block_public_acls().unwrap_or(false),
+                ignore_public_acls: c.ignore_public_acls().unwrap_or(false),
+                block_public_policy: c.block_public_policy().unwrap_or(false),
+                restrict_public_buckets: c.restrict_public_buckets().unwrap_or(false),
+            })
+            .unwrap_or_default()),
+        Err(e)
+            if e.code() == Some("NoSuchPublicAccessBlockConfiguration")
+                || is_unsupported_error(e.code()) =>
+        {
+            Ok(PublicAccessBlockSettings::default())
+        }
+        Err(e) => Err(format!("Failed to get public access block: {}", e)),
+    }
+}
+
+/// 读取 Bucket 策略原文，未设置时返回 None
+pub(crate) async fn load_bucket_policy(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Option<String>, String> {
+    match client.get_bucket_policy().bucket(bucket).send().await {
+        Ok(result) => Ok(result.policy().map(|s| s.to_string())),
+        Err(e) if e.code() == Some("NoSuchBucketPolicy") || is_unsupported_error(e.code()) => {
+            Ok(None)
+        }
+        Err(e) => Err(format!("Failed to get bucket policy: {}", e)),
+    }
+}
+
+/// 结合公共访问阻止设置判断某一来源是否公开，无法确定时为 None
+fn effective_public(public: Option<bool>, blocked: Option<bool>) -> Option<bool> {
+    match (public, blocked) {
+        (_, Some(true)) | (Some(false), _) => Some(false),
+        (Some(true), Some(false)) => Some(true),
+        _ => None,
+    }
+}
+
+/// 计算 Bucket 是否可公开访问
+///
+/// 综合 Bucket 策略状态、ACL 和 Bucket 级公共访问阻止设置，不包含账户级设置。
+/// 无权读取相关配置而无法确定时返回 None。
+pub(crate) async fn bucket_is_public(client: &aws_sdk_s3::Client, bucket: &str) -> Option<bool> {
+    let (block, policy_status, acl) = tokio::join!(
+        load_public_access_block(client, bucket),
+        client.get_bucket_policy_status().bucket(bucket).send(),
+        client.get_bucket_acl().bucket(bucket).send(),
+    );
+    let block = block.ok();
+
+    let policy_public = match policy_status {
+        Ok(r) => Some(
+            r.policy_status()
+                .and_then(|s| s.is_public())
+                .unwrap_or(false),
+        ),
+        Err(e) if e.code() == Some("NoSuchBucketPolicy") => Some(false),
+        Err(_) => None,
+    };
+    let policy_public = effective_public(
+        policy_public,
+        block.as_ref().map(|b| b.restrict_public_buckets),
+    );
+    let acl_public = effective_public(
+        acl.ok().map(|r| grants_are_public(r.grants())),
+        block.as_ref().map(|b| b.ignore_public_acls),
+    );
+
+    match (policy_public, acl_public) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+/// 获取 Bucket 公共访问阻止设置
+#[tauri::command]
+pub async fn get_public_access_block(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<PublicAccessBlockSettings, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    load_public_access_block(client.client(), &bucket_name).await
+}
+
+/// 设置 Bucket 公共访问阻止设置
+#[tauri::command]
+pub async fn put_public_access_block(
+    config: S3Config,
+    bucket_name: String,
+    settings: PublicAccessBlockSettings,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_public_access_block()
+        .bucket(&bucket_name)
+        .public_access_block_configuration(build_public_access_block(&settings))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put public access block: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除 Bucket 公共访问阻止设置
+#[tauri::command]
+pub async fn delete_public_access_block(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .delete_public_access_block()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete public access block: {}", e))?;
+
+    Ok(())
+}
+
+/// 获取 Bucket 是否可公开访问，无权读取相关配置时返回 None
+#[tauri::command]
+pub async fn get_bucket_public_status(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<Option<bool>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    Ok(bucket_is_public(client.client(), &bucket_name).await)
+}
+
+/// 获取 Bucket ACL
+#[tauri::command]
+pub async fn get_bucket_acl(config: S3Config, bucket_name: String) -> Result<AclInfo, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = client
+        .client()
+        .get_bucket_acl()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get bucket ACL: {}", e))?;
+
+    Ok(to_acl_info(result.owner(), result.grants()))
+}
+
+/// 设置 Bucket ACL
+///
+/// `acl` 和 `canned_acl` 必须且只能指定其一。
+#[tauri::command]
+pub async fn put_bucket_acl(
+    config: S3Config,
+    bucket_name: String,
+    acl: Option<AclInfo>,
+    canned_acl: Option<String>,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let builder = client.client().put_bucket_acl().bucket(&bucket_name);
+    let builder = match (acl, canned_acl) {
+        (Some(acl), None) => builder.access_control_policy(build_access_control_policy(acl)?),
+        (None, Some(canned)) => builder.acl(parse_bucket_canned_acl(&canned)?),
+        _ => return Err("Specify either grants or a canned ACL".to_string()),
+    };
+
+    builder
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket ACL: {}", e))?;
+
+    Ok(())
+}
+
+/// 获取 Object ACL
+#[tauri::command]
+pub async fn get_object_acl(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+) -> Result<AclInfo, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = client
+        .client()
+        .get_object_acl()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get object ACL: {}", e))?;
+
+    Ok(to_acl_info(result.owner(), result.grants()))
+}
+
+/// 设置 Object ACL
+///
+/// `acl` 和 `canned_acl` 必须且只能指定其一。
+#[tauri::command]
+pub async fn put_object_acl(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    acl: Option<AclInfo>,
+    canned_acl: Option<String>,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let builder = client
+        .client()
+        .put_object_acl()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id);
+    let builder = match (acl, canned_acl) {
+        (Some(acl), None) => builder.access_control_policy(build_access_control_policy(acl)?),
+        (None, Some(canned)) => builder.acl(parse_object_canned_acl(&canned)?),
+        _ => return Err("Specify either grants or a canned ACL".to_string()),
+    };
+
+    builder
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put object ACL: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant(grantee_type: &str, uri: Option<&str>, permission: &str) -> GrantInfo {
+        GrantInfo {
+            grantee_type: grantee_type.to_string(),
+            grantee_id: None,
+            grantee_uri: uri.map(|s| s.to_string()),
+            grantee_email: None,
+            grantee_display_name: None,
+            permission: permission.to_string(),
+        }
+    }
+
+    fn acl(grants: Vec<GrantInfo>) -> AclInfo {
+        AclInfo {
+            owner_id: Some("owner".to_string()),
+            owner_display_name: None,
+            grants,
+            is_public: false,
+        }
+    }
+
+    #[test]
+    fn acl_round_trip_detects_public_grants() {
+        let policy =
+            build_access_control_policy(acl(vec![grant("Group", Some(ALL_USERS_URI), "READ")]))
+                .unwrap();
+        let info = to_acl_info(policy.owner(), policy.grants());
+        assert!(info.is_public);
+        assert_eq!(info.owner_id.as_deref(), Some("owner"));
+        assert_eq!(info.grants[0].grantee_uri.as_deref(), Some(ALL_USERS_URI));
+        assert_eq!(info.grants[0].permission, "READ");
+
+        let policy = build_access_control_policy(acl(vec![grant(
+            "Group",
+            Some("http://acs.amazonaws.com/groups/s3/LogDelivery"),
+            "WRITE",
+        )]))
+        .unwrap();
+        assert!(!grants_are_public(policy.grants()));
+    }
+
+    #[test]
+    fn build_access_control_policy_rejects_unknown_values() {
+        assert!(build_access_control_policy(acl(vec![grant("Everyone", None, "READ")])).is_err());
+        assert!(build_access_control_policy(acl(vec![grant(
+            "Group",
+            Some(ALL_USERS_URI),
+            "DELETE"
+        )]))
+        .is_err());
+    }
+
+    #[test]
+    fn parse_canned_acls() {
+        assert_eq!(
+            parse_bucket_canned_acl("public-read").unwrap(),
+            BucketCannedAcl::PublicRead
+        );
+        assert!(parse_bucket_canned_acl("bucket-owner-full-control").is_err());
+        assert_eq!(
+            parse_object_canned_acl("bucket-owner-full-control").unwrap(),
+            ObjectCannedAcl::BucketOwnerFullControl
+        );
+        assert!(parse_object_canned_acl("public").is_err());
+    }
+
+    #[test]
+    fn public_status_is_unknown_without_permission() {
+        assert_eq!(effective_public(Some(true), Some(false)), Some(true));
+        assert_eq!(effective_public(Some(true), Some(true)), Some(false));
+        assert_eq!(effective_public(None, Some(true)), Some(false));
+        assert_eq!(effective_public(Some(false), None), Some(false));
+        assert_eq!(effective_public(Some(true), None), None);
+        assert_eq!(effective_public(None, Some(false)), None);
+    }
+}
@@ -0,0 +1,854 @@
+use crate::s3::types::{
+    format_timestamp, is_unsupported_error, BatchFailure, BlueprintApplyResult,
+    BlueprintDifference, BucketBlueprint, CorsRuleInfo, LifecycleRuleInfo, LifecycleTransitionInfo,
+    S3Config,
+};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_s3::types::{
+    AbortIncompleteMultipartUpload, BucketLifecycleConfiguration, BucketVersioningStatus,
+    CorsConfiguration, CorsRule, ExpirationStatus, LifecycleExpiration, LifecycleRule,
+    LifecycleRuleAndOperator, LifecycleRuleFilter, NoncurrentVersionExpiration,
+    NoncurrentVersionTransition, Tag, Transition, TransitionStorageClass, VersioningConfiguration,
+};
+
+// 蓝图格式版本
+const BLUEPRINT_VERSION: u32 = 1;
+
+/// 蓝图配置项，名称与 BucketBlueprint 的字段一致
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Versioning,
+    PublicAccessBlock,
+    Policy,
+    Encryption,
+    Lifecycle,
+    Cors,
+    Tags,
+    Website,
+}
+
+impl Section {
+    // 应用顺序：先设置公共访问阻止，再写入策略
+    const ALL: [Section; 8] = [
+        Section::Versioning,
+        Section::PublicAccessBlock,
+        Section::Policy,
+        Section::Encryption,
+        Section::Lifecycle,
+        Section::Cors,
+        Section::Tags,
+        Section::Website,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Section::Versioning => "versioning",
+            Section::PublicAccessBlock => "public_access_block",
+            Section::Policy => "policy",
+            Section::Encryption => "encryption",
+            Section::Lifecycle => "lifecycle",
+            Section::Cors => "cors",
+            Section::Tags => "tags",
+            Section::Website => "website",
+        }
+    }
+
+    fn parse(name: &str) -> Result<Self, String> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.name() == name)
+            .ok_or_else(|| format!("Unknown blueprint section: {}", name))
+    }
+}
+
+// 策略中需要替换 Bucket 名称的 ARN 前缀
+const BUCKET_ARN_PREFIXES: &[&str] = &["arn:aws:s3:::", "arn:aws-cn:s3:::", "arn:aws-us-gov:s3:::"];
+
+fn parse_date(date: Option<String>) -> Result<Option<DateTime>, String> {
+    date.map(|d| {
+        DateTime::from_str(&d, DateTimeFormat::DateTime)
+            .map_err(|e| format!("Invalid date '{}': {}", d, e))
+    })
+    .transpose()
+}
+
+fn parse_transition_class(storage_class: &str) -> Result<TransitionStorageClass, String> {
+    if TransitionStorageClass::values().contains(&storage_class) {
+        Ok(TransitionStorageClass::from(storage_class))
+    } else {
+        Err(format!(
+            "Unsupported transition storage class: {}",
+            storage_class
+        ))
+    }
+}
+
+/// 将 SDK 的生命周期规则转换为 LifecycleRuleInfo
+fn lifecycle_from_sdk(rule: &LifecycleRule) -> LifecycleRuleInfo {
+    let filter = rule.filter();
+    let and = filter.and_then(|f| f.and());
+    let expiration = rule.expiration();
+    let noncurrent_expiration = rule.noncurrent_version_expiration();
+
+    // 兼容旧版规则中直接设置的 Prefix
+    #[allow(deprecated)]
+    let legacy_prefix = rule.prefix();
+    let prefix = and
+        .and_then(|a| a.prefix())
+        .or_else(|| filter.and_then(|f| f.prefix()))
+        .or(legacy_prefix)
+        .filter(|p| !p.is_empty())
+        .map(|s| s.to_string());
+    let tags = match and {
+        Some(and) => super::tagging::to_tag_infos(and.tags()),
+        None => filter
+            .and_then(|f| f.tag())
+            .map(|tag| super::tagging::to_tag_infos(std::slice::from_ref(tag)))
+            .unwrap_or_default(),
+    };
+
+    LifecycleRuleInfo {
+        id: rule.id().map(|s| s.to_string()),
+        enabled: *rule.status() == ExpirationStatus::Enabled,
+        prefix,
+        tags,
+        object_size_greater_than: and
+            .and_then(|a| a.object_size_greater_than())
+            .or_else(|| filter.and_then(|f| f.object_size_greater_than())),
+        object_size_less_than: and
+            .and_then(|a| a.object_size_less_than())
+            .or_else(|| filter.and_then(|f| f.object_size_less_than())),
+        expiration_days: expiration.and_then(|e| e.days()),
+        expiration_date: expiration.and_then(|e| e.date()).map(format_timestamp),
+        expired_object_delete_marker: expiration.and_then(|e| e.expired_object_delete_marker()),
+        transitions: rule
+            .transitions()
+            .iter()
+            .map(|t| LifecycleTransitionInfo {
+                days: t.days(),
+                date: t.date().map(format_timestamp),
+                storage_class: t
+                    .storage_class()
+                    .map(|c| c.as_str().to_string())
+                    .unwrap_or_default(),
+            })
+            .collect(),
+        noncurrent_expiration_days: noncurrent_expiration.and_then(|e| e.noncurrent_days()),
+        newer_noncurrent_versions: noncurrent_expiration
+            .and_then(|e| e.newer_noncurrent_versions()),
+        noncurrent_transitions: rule
+            .noncurrent_version_transitions()
+            .iter()
+            .map(|t| LifecycleTransitionInfo {
+                days: t.noncurrent_days(),
+                date: None,
+                storage_class: t
+                    .storage_class()
+                    .map(|c| c.as_str().to_string())
+                    .unwrap_or_default(),
+            })
+            .collect(),
+        abort_incomplete_multipart_days: rule
+            .abort_incomplete_multipart_upload()
+            .and_then(|a| a.days_after_initiation()),
+    }
+}
+
+/// 校验 LifecycleRuleInfo 并转换为 SDK 类型
+fn lifecycle_to_sdk(rule: LifecycleRuleInfo) -> Result<LifecycleRule, String> {
+    let tags = rule
+        .tags
+        .iter()
+        .map(|t| {
+            Tag::builder()
+                .key(&t.key)
+                .value(&t.value)
+                .build()
+                .map_err(|e| e.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // 多个过滤条件时必须使用 And 组合
+    let conditions = tags.len()
+        + usize::from(rule.prefix.is_some())
+        + usize::from(rule.object_size_greater_than.is_some())
+        + usize::from(rule.object_size_less_than.is_some());
+    let filter = if conditions > 1 {
+        LifecycleRuleFilter::builder()
+            .and(
+                LifecycleRuleAndOperator::builder()
+                    .set_prefix(rule.prefix)
+                    .set_tags(Some(tags))
+                    .set_object_size_greater_than(rule.object_size_greater_than)
+                    .set_object_size_less_than(rule.object_size_less_than)
+                    .build(),
+            )
+            .build()
+    } else if let Some(tag) = tags.into_iter().next() {
+        LifecycleRuleFilter::builder().tag(tag).build()
+    } else if let Some(size) = rule.object_size_greater_than {
+        LifecycleRuleFilter::builder()
+            .object_size_greater_than(size)
+            .build()
+    } else if let Some(size) = rule.object_size_less_than {
+        LifecycleRuleFilter::builder()
+            .object_size_less_than(size)
+            .build()
+    } else {
+        LifecycleRuleFilter::builder()
+            .prefix(rule.prefix.unwrap_or_default())
+            .build()
+    };
+
+    let expiration = if rule.expiration_days.is_some()
+        || rule.expiration_date.is_some()
+        || rule.expired_object_delete_marker.is_some()
+    {
+        Some(
+            LifecycleExpiration::builder()
+                .set_days(rule.expiration_days)
+                .set_date(parse_date(rule.expiration_date)?)
+                .set_expired_object_delete_marker(rule.expired_object_delete_marker)
+                .build(),
+        )
+    } else {
+        None
+    };
+
+    let noncurrent_expiration =
+        if rule.noncurrent_expiration_days.is_some() || rule.newer_noncurrent_versions.is_some() {
+            Some(
+                NoncurrentVersionExpiration::builder()
+                    .set_noncurrent_days(rule.noncurrent_expiration_days)
+                    .set_newer_noncurrent_versions(rule.newer_noncurrent_versions)
+                    .build(),
+            )
+        } else {
+            None
+        };
+
+    let mut transitions = Vec::with_capacity(rule.transitions.len());
+    for t in rule.transitions {
+        transitions.push(
+            Transition::builder()
+                .set_days(t.days)
+                .set_date(parse_date(t.date)?)
+                .storage_class(parse_transition_class(&t.storage_class)?)
+                .build(),
+        );
+    }
+
+    let mut noncurrent_transitions = Vec::with_capacity(rule.noncurrent_transitions.len());
+    for t in rule.noncurrent_transitions {
+        noncurrent_transitions.push(
+            NoncurrentVersionTransition::builder()
+                .set_noncurrent_days(t.days)
+                .storage_class(parse_transition_class(&t.storage_class)?)
+                .build(),
+        );
+    }
+
+    LifecycleRule::builder()
+        .set_id(rule.id)
+        .status(if rule.enabled {
+            ExpirationStatus::Enabled
+        } else {
+            ExpirationStatus::Disabled
+        })
+        .filter(filter)
+        .set_expiration(expiration)
+        .set_transitions(Some(transitions))
+        .set_noncurrent_version_expiration(noncurrent_expiration)
+        .set_noncurrent_version_transitions(Some(noncurrent_transitions))
+        .set_abort_incomplete_multipart_upload(rule.abort_incomplete_multipart_days.map(|days| {
+            AbortIncompleteMultipartUpload::builder()
+                .days_after_initiation(days)
+                .build()
+        }))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+fn cors_from_sdk(rule: &CorsRule) -> CorsRuleInfo {
+    CorsRuleInfo {
+        id: rule.id().map(|s| s.to_string()),
+        allowed_methods: rule.allowed_methods().to_vec(),
+        allowed_origins: rule.allowed_origins().to_vec(),
+        allowed_headers: rule.allowed_headers().to_vec(),
+        expose_headers: rule.expose_headers().to_vec(),
+        max_age_seconds: rule.max_age_seconds(),
+    }
+}
+
+fn cors_to_sdk(rule: CorsRuleInfo) -> Result<CorsRule, String> {
+    if rule.allowed_methods.is_empty() || rule.allowed_origins.is_empty() {
+        return Err("A CORS rule needs at least one allowed method and origin".to_string());
+    }
+
+    CorsRule::builder()
+        .set_id(rule.id)
+        .set_allowed_methods(Some(rule.allowed_methods))
+        .set_allowed_origins(Some(rule.allowed_origins))
+        .set_allowed_headers(Some(rule.allowed_headers))
+        .set_expose_headers(Some(rule.expose_headers))
+        .set_max_age_seconds(rule.max_age_seconds)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+async fn load_bucket_versioning(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Option<String>, String> {
+    match client.get_bucket_versioning().bucket(bucket).send().await {
+        Ok(result) => Ok(result.status().map(|s| s.as_str().to_string())),
+        Err(e) if is_unsupported_error(e.code()) => Ok(None),
+        Err(e) => Err(format!("Failed to get bucket versioning: {}", e)),
+    }
+}
+
+async fn load_bucket_lifecycle(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Vec<LifecycleRuleInfo>, String> {
+    match client
+        .get_bucket_lifecycle_configuration()
+        .bucket(bucket)
+        .send()
+        .await
+    {
+        Ok(result) => Ok(result.rules().iter().map(lifecycle_from_sdk).collect()),
+        Err(e)
+            if e.code() == Some("NoSuchLifecycleConfiguration")
+                || is_unsupported_error(e.code()) =>
+        {
+            Ok(Vec::new())
+        }
+        Err(e) => Err(format!("Failed to get bucket lifecycle: {}", e)),
+    }
+}
+
+async fn load_bucket_cors(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Vec<CorsRuleInfo>, String> {
+    match client.get_bucket_cors().bucket(bucket).send().await {
+        Ok(result) => Ok(result.cors_rules().iter().map(cors_from_sdk).collect()),
+        Err(e) if e.code() == Some("NoSuchCORSConfiguration") || is_unsupported_error(e.code()) => {
+            Ok(Vec::new())
+        }
+        Err(e) => Err(format!("Failed to get bucket CORS: {}", e)),
+    }
+}
+
+/// 取出单个配置项的读取结果，失败时记录到 `unreadable` 并视为未配置
+fn section_value<T: Default>(
+    section: Section,
+    result: Result<T, String>,
+    unreadable: &mut Vec<BatchFailure>,
+) -> T {
+    result.unwrap_or_else(|message| {
+        unreadable.push(BatchFailure {
+            key: section.name().to_string(),
+            code: None,
+            message,
+        });
+        T::default()
+    })
+}
+
+/// 读取 Bucket 的完整配置并生成蓝图
+///
+/// 服务商不支持的配置项视为未配置，其他读取失败的项记录在 `unreadable` 中，不影响其余项。
+async fn load_blueprint(client: &aws_sdk_s3::Client, bucket: &str) -> BucketBlueprint {
+    let (versioning, lifecycle, policy, cors, encryption, tags, website, public_access_block) = tokio::join!(
+        load_bucket_versioning(client, bucket),
+        load_bucket_lifecycle(client, bucket),
+        super::access::load_bucket_policy(client, bucket),
+        load_bucket_cors(client, bucket),
+        super::encryption::load_bucket_encryption(client, bucket),
+        super::tagging::load_bucket_tagging(client, bucket),
+        super::website::load_bucket_website(client, bucket),
+        super::access::load_public_access_block(client, bucket),
+    );
+
+    let policy = policy.and_then(|policy| {
+        policy
+            .map(|p| serde_json::from_str(&p).map_err(|e| format!("Invalid bucket policy: {}", e)))
+            .transpose()
+    });
+    // 全部为 false 与未配置等价
+    let public_access_block = public_access_block.map(|b| {
+        Some(b).filter(|b| {
+            b.block_public_acls
+                || b.ignore_public_acls
+                || b.block_public_policy
+                || b.restrict_public_buckets
+        })
+    });
+
+    let mut unreadable = Vec::new();
+    BucketBlueprint {
+        version: BLUEPRINT_VERSION,
+        source_bucket: bucket.to_string(),
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        versioning: section_value(Section::Versioning, versioning, &mut unreadable),
+        lifecycle: section_value(Section::Lifecycle, lifecycle, &mut unreadable),
+        policy: section_value(Section::Policy, policy, &mut unreadable),
+        cors: section_value(Section::Cors, cors, &mut unreadable),
+        encryption: section_value(Section::Encryption, encryption, &mut unreadable),
+        tags: section_value(Section::Tags, tags, &mut unreadable),
+        website: section_value(Section::Website, website, &mut unreadable),
+        public_access_block: section_value(
+            Section::PublicAccessBlock,
+            public_access_block,
+            &mut unreadable,
+        ),
+        unreadable,
+    }
+}
+
+/// 配置项是否在导出时读取失败
+fn is_unreadable(blueprint: &BucketBlueprint, section: Section) -> bool {
+    blueprint.unreadable.iter().any(|f| f.key == section.name())
+}
+
+/// 将策略中源 Bucket 的 ARN 替换为目标 Bucket
+fn retarget_policy(policy: &serde_json::Value, from: &str, to: &str) -> serde_json::Value {
+    if from.is_empty() || from == to {
+        return policy.clone();
+    }
+
+    let mut text = policy.to_string();
+    for prefix in BUCKET_ARN_PREFIXES {
+        for terminator in ["\"", "/"] {
+            text = text.replace(
+                &format!("{}{}{}", prefix, from, terminator),
+                &format!("{}{}{}", prefix, to, terminator),
+            );
+        }
+    }
+
+    serde_json::from_str(&text).unwrap_or_else(|_| policy.clone())
+}
+
+/// 比较两个蓝图，左侧蓝图的策略会先按右侧 Bucket 名称替换
+///
+/// 任一侧读取失败的配置项不参与比较。
+fn diff_blueprints(
+    left: &BucketBlueprint,
+    right: &BucketBlueprint,
+) -> Result<Vec<BlueprintDifference>, String> {
+    let normalize = |blueprint: &BucketBlueprint, target: &str| {
+        let mut blueprint = blueprint.clone();
+        blueprint.policy = blueprint
+            .policy
+            .as_ref()
+            .map(|p| retarget_policy(p, &blueprint.source_bucket, target));
+        // 标签顺序无意义
+        blueprint.tags.sort_by(|a, b| a.key.cmp(&b.key));
+        serde_json::to_value(blueprint).map_err(|e| e.to_string())
+    };
+
+    let sections: Vec<Section> = Section::ALL
+        .into_iter()
+        .filter(|s| !is_unreadable(left, *s) && !is_unreadable(right, *s))
+        .collect();
+    let left = normalize(left, &right.source_bucket)?;
+    let right = normalize(right, &right.source_bucket)?;
+
+    Ok(sections
+        .into_iter()
+        .filter_map(|section| {
+            let l = left.get(section.name()).cloned().unwrap_or_default();
+            let r = right.get(section.name()).cloned().unwrap_or_default();
+            (l != r).then(|| BlueprintDifference {
+                section: section.name().to_string(),
+                left: l,
+                right: r,
+            })
+        })
+        .collect())
+}
+
+/// 导出 Bucket 配置蓝图
+#[tauri::command]
+pub async fn export_bucket_blueprint(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<BucketBlueprint, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    Ok(load_blueprint(client.client(), &bucket_name).await)
+}
+
+/// 比较两个蓝图的差异
+#[tauri::command]
+pub async fn diff_bucket_blueprints(
+    left: BucketBlueprint,
+    right: BucketBlueprint,
+) -> Result<Vec<BlueprintDifference>, String> {
+    diff_blueprints(&left, &right)
+}
+
+/// 比较蓝图与 Bucket 当前配置的差异（蓝图在左侧）
+#[tauri::command]
+pub async fn diff_bucket_blueprint_live(
+    config: S3Config,
+    bucket_name: String,
+    blueprint: BucketBlueprint,
+) -> Result<Vec<BlueprintDifference>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let live = load_blueprint(client.client(), &bucket_name).await;
+
+    diff_blueprints(&blueprint, &live)
+}
+
+/// 只接受当前格式版本的蓝图，其他版本（包括更新的版本）的字段含义可能不同
+fn check_version(blueprint: &BucketBlueprint) -> Result<(), String> {
+    if blueprint.version != BLUEPRINT_VERSION {
+        return Err(format!(
+            "Unsupported blueprint version {} (expected {})",
+            blueprint.version, BLUEPRINT_VERSION
+        ));
+    }
+    Ok(())
+}
+
+/// 将配置项的写入结果转换为失败记录
+fn section_result<T, E>(name: &str, result: Result<T, E>) -> Result<(), BatchFailure>
+where
+    E: ProvideErrorMetadata + std::fmt::Display,
+{
+    result
+        .map(|_| ())
+        .map_err(|e| BatchFailure::from_sdk_error(name, &e))
+}
+
+/// 将蓝图应用到 Bucket
+///
+/// `sections` 为空时应用全部配置项。蓝图中未配置的项会从目标 Bucket 删除，
+/// 导出时读取失败的项不会修改并记为失败，单个配置项失败不会中断其余项。
+/// 蓝图版本不受支持时不做任何修改直接返回错误。
+#[tauri::command]
+pub async fn apply_bucket_blueprint(
+    config: S3Config,
+    bucket_name: String,
+    blueprint: BucketBlueprint,
+    sections: Option<Vec<String>>,
+) -> Result<BlueprintApplyResult, String> {
+    check_version(&blueprint)?;
+    let selected = match sections {
+        Some(sections) if !sections.is_empty() => sections
+            .iter()
+            .map(|s| Section::parse(s))
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => Section::ALL.to_vec(),
+    };
+    let unreadable: Vec<Section> = Section::ALL
+        .into_iter()
+        .filter(|s| is_unreadable(&blueprint, *s))
+        .collect();
+
+    // 在发送任何请求前校验所有配置
+    let versioning = blueprint
+        .versioning
+        .as_deref()
+        .map(|status| {
+            if BucketVersioningStatus::values().contains(&status) {
+                Ok(BucketVersioningStatus::from(status))
+            } else {
+                Err(format!("Unsupported versioning status: {}", status))
+            }
+        })
+        .transpose()?;
+    let public_access_block = blueprint
+        .public_access_block
+        .as_ref()
+        .map(super::access::build_public_access_block);
+    let policy = blueprint
+        .policy
+        .as_ref()
+        .map(|p| retarget_policy(p, &blueprint.source_bucket, &bucket_name).to_string());
+    let encryption = blueprint
+        .encryption
+        .map(super::encryption::build_encryption_configuration)
+        .transpose()?;
+    let lifecycle = if blueprint.lifecycle.is_empty() {
+        None
+    } else {
+        let rules = blueprint
+            .lifecycle
+            .into_iter()
+            .map(lifecycle_to_sdk)
+            .collect::<Result<Vec<_>, _>>()?;
+        Some(
+            BucketLifecycleConfiguration::builder()
+                .set_rules(Some(rules))
+                .build()
+                .map_err(|e| e.to_string())?,
+        )
+    };
+    let cors = if blueprint.cors.is_empty() {
+        None
+    } else {
+        let rules = blueprint
+            .cors
+            .into_iter()
+            .map(cors_to_sdk)
+            .collect::<Result<Vec<_>, _>>()?;
+        Some(
+            CorsConfiguration::builder()
+                .set_cors_rules(Some(rules))
+                .build()
+                .map_err(|e| e.to_string())?,
+        )
+    };
+    let tagging = if blueprint.tags.is_empty() {
+        None
+    } else {
+        Some(super::tagging::build_tagging(
+            &blueprint.tags,
+            super::tagging::MAX_BUCKET_TAGS,
+        )?)
+    };
+    let website = blueprint
+        .website
+        .map(super::website::website_to_sdk)
+        .transpose()?;
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let s3 = client.client();
+    let bucket = bucket_name.as_str();
+
+    let mut applied = Vec::new();
+    let mut failures = Vec::new();
+
+    for section in Section::ALL {
+        if !selected.contains(&section) {
+            continue;
+        }
+        let name = section.name();
+        if unreadable.contains(&section) {
+            failures.push(BatchFailure {
+                key: name.to_string(),
+                code: None,
+                message: "Section could not be read when the blueprint was exported".to_string(),
+            });
+            continue;
+        }
+
+        let result = match section {
+            Section::Versioning => {
+                // 版本控制无法关闭，只能暂停
+                let status = match versioning.clone() {
+                    Some(status) => Some(status),
+                    None => match s3.get_bucket_versioning().bucket(bucket).send().await {
+                        Ok(current) => current
+                            .status()
+                            .is_some_and(|s| *s == BucketVersioningStatus::Enabled)
+                            .then_some(BucketVersioningStatus::Suspended),
+                        Err(e) => {
+                            failures.push(BatchFailure::from_sdk_error(name, &e));
+                            continue;
+                        }
+                    },
+                };
+                match status {
+                    Some(status) => section_result(
+                        name,
+                        s3.put_bucket_versioning()
+                            .bucket(bucket)
+                            .versioning_configuration(
+                                VersioningConfiguration::builder().status(status).build(),
+                            )
+                            .send()
+                            .await,
+                    ),
+                    None => Ok(()),
+                }
+            }
+            Section::PublicAccessBlock => match public_access_block.clone() {
+                Some(configuration) => section_result(
+                    name,
+                    s3.put_public_access_block()
+                        .bucket(bucket)
+                        .public_access_block_configuration(configuration)
+                        .send()
+                        .await,
+                ),
+                None => section_result(
+                    name,
+                    s3.delete_public_access_block().bucket(bucket).send().await,
+                ),
+            },
+            Section::Policy => match policy.clone() {
+                Some(policy) => section_result(
+                    name,
+                    s3.put_bucket_policy()
+                        .bucket(bucket)
+                        .policy(policy)
+                        .send()
+                        .await,
+                ),
+                None => section_result(name, s3.delete_bucket_policy().bucket(bucket).send().await),
+            },
+            Section::Encryption => match encryption.clone() {
+                Some(configuration) => section_result(
+                    name,
+                    s3.put_bucket_encryption()
+                        .bucket(bucket)
+                        .server_side_encryption_configuration(configuration)
+                        .send()
+                        .await,
+                ),
+                None => section_result(
+                    name,
+                    s3.delete_bucket_encryption().bucket(bucket).send().await,
+                ),
+            },
+            Section::Lifecycle => match lifecycle.clone() {
+                Some(configuration) => section_result(
+                    name,
+                    s3.put_bucket_lifecycle_configuration()
+                        .bucket(bucket)
+                        .lifecycle_configuration(configuration)
+                        .send()
+                        .await,
+                ),
+                None => section_result(
+                    name,
+                    s3.delete_bucket_lifecycle().bucket(bucket).send().await,
+                ),
+            },
+            Section::Cors => match cors.clone() {
+                Some(configuration) => section_result(
+                    name,
+                    s3.put_bucket_cors()
+                        .bucket(bucket)
+                        .cors_configuration(configuration)
+                        .send()
+                        .await,
+                ),
+                None => section_result(name, s3.delete_bucket_cors().bucket(bucket).send().await),
+            },
+            Section::Tags => match tagging.clone() {
+                Some(tagging) => section_result(
+                    name,
+                    s3.put_bucket_tagging()
+                        .bucket(bucket)
+                        .tagging(tagging)
+                        .send()
+                        .await,
+                ),
+                None => {
+                    section_result(name, s3.delete_bucket_tagging().bucket(bucket).send().await)
+                }
+            },
+            Section::Website => match website.clone() {
+                Some(configuration) => section_result(
+                    name,
+                    s3.put_bucket_website()
+                        .bucket(bucket)
+                        .website_configuration(configuration)
+                        .send()
+                        .await,
+                ),
+                None => {
+                    section_result(name, s3.delete_bucket_website().bucket(bucket).send().await)
+                }
+            },
+        };
+
+        match result {
+            Ok(()) => applied.push(name.to_string()),
+            Err(failure) => failures.push(failure),
+        }
+    }
+
+    Ok(BlueprintApplyResult { applied, failures })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3::types::TagInfo;
+    use serde_json::json;
+
+    #[test]
+    fn only_current_version_is_supported() {
+        let blueprint = |version| BucketBlueprint {
+            version,
+            ..Default::default()
+        };
+        assert!(check_version(&blueprint(BLUEPRINT_VERSION)).is_ok());
+        assert!(check_version(&blueprint(0)).is_err());
+        assert!(check_version(&blueprint(BLUEPRINT_VERSION + 1)).is_err());
+    }
+
+    #[test]
+    fn retarget_policy_replaces_only_the_source_bucket() {
+        let policy = json!({
+            "Statement": [{
+                "Resource": ["arn:aws:s3:::src", "arn:aws:s3:::src/*", "arn:aws:s3:::src-other/*"]
+            }]
+        });
+        let retargeted = retarget_policy(&policy, "src", "dst");
+        assert_eq!(
+            retargeted["Statement"][0]["Resource"],
+            json!([
+                "arn:aws:s3:::dst",
+                "arn:aws:s3:::dst/*",
+                "arn:aws:s3:::src-other/*"
+            ])
+        );
+    }
+
+    #[test]
+    fn retarget_policy_handles_other_partitions() {
+        let policy = json!({"Resource": "arn:aws-cn:s3:::src/*"});
+        assert_eq!(
+            retarget_policy(&policy, "src", "dst"),
+            json!({"Resource": "arn:aws-cn:s3:::dst/*"})
+        );
+    }
+
+    #[test]
+    fn section_names_round_trip() {
+        for section in Section::ALL {
+            assert_eq!(Section::parse(section.name()), Ok(section));
+        }
+        assert!(Section::parse("replication").is_err());
+    }
+
+    #[test]
+    fn diff_skips_unreadable_sections() {
+        let left = BucketBlueprint {
+            source_bucket: "a".to_string(),
+            versioning: Some("Enabled".to_string()),
+            tags: vec![TagInfo {
+                key: "env".to_string(),
+                value: "prod".to_string(),
+            }],
+            ..Default::default()
+        };
+        let right = BucketBlueprint {
+            source_bucket: "b".to_string(),
+            unreadable: vec![BatchFailure {
+                key: "tags".to_string(),
+                code: None,
+                message: "Access Denied".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let sections: Vec<String> = diff_blueprints(&left, &right)
+            .unwrap()
+            .into_iter()
+            .map(|d| d.section)
+            .collect();
+        assert_eq!(sections, vec!["versioning".to_string()]);
+    }
+}
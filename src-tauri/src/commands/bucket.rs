@@ -1,5 +1,35 @@
-use crate::s3::types::{BucketInfo, S3Config};
+use crate::s3::types::{
+    format_timestamp, BatchFailure, BatchProgress, BatchResult, BucketEnriched, BucketInfo,
+    CreateBucketOptions, ListBucketsResult, PublicAccessBlockSettings, S3Config,
+};
 use crate::s3::client::S3Client;
+use crate::s3::TaskManager;
+use aws_sdk_s3::types::{
+    Bucket, BucketCannedAcl, BucketVersioningStatus, CreateBucketConfiguration, ObjectIdentifier,
+    ObjectOwnership, Payer, RequestPaymentConfiguration, VersioningConfiguration,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::Emitter;
+use tokio::sync::{Mutex, Semaphore};
+use tokio::task::JoinSet;
+
+// 补充 Bucket 信息时的并发数
+const ENRICH_CONCURRENCY: usize = 8;
+
+fn bucket_info_from_sdk(bucket: &Bucket) -> BucketInfo {
+    BucketInfo {
+        name: bucket.name().unwrap_or("").to_string(),
+        creation_date: bucket
+            .creation_date()
+            .map(format_timestamp)
+            .unwrap_or_default(),
+        region: bucket.bucket_region().map(|s| s.to_string()),
+        is_public: None,
+        versioning: None,
+        tags: None,
+    }
+}
 
 /// 列出所有 Buckets
 #[tauri::command]
@@ -13,20 +43,151 @@ pub async fn list_buckets(config: S3Config) -> Result<Vec<BucketInfo>, String> {
         .await
         .map_err(|e| e.to_string())?;
 
+    let buckets = result.buckets().iter().map(bucket_info_from_sdk).collect();
+
+    Ok(buckets)
+}
+
+/// 分页列出 Buckets
+#[tauri::command]
+pub async fn list_buckets_page(
+    config: S3Config,
+    prefix: Option<String>,
+    continuation_token: Option<String>,
+    max_buckets: Option<i32>,
+) -> Result<ListBucketsResult, String> {
+    let prefix = prefix.filter(|p| !p.is_empty());
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = client
+        .client()
+        .list_buckets()
+        .set_prefix(prefix.clone())
+        .set_continuation_token(continuation_token)
+        .set_max_buckets(max_buckets)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to list buckets: {}", e))?;
+
+    // 部分 S3 兼容服务会忽略 prefix 参数，在本地再过滤一次
     let buckets = result
         .buckets()
         .iter()
-        .map(|b| BucketInfo {
-            name: b.name().unwrap_or("").to_string(),
-            creation_date: b
-                .creation_date()
-                .map(|d| format!("{:?}", d))
-                .unwrap_or_default(),
-            region: None,
-        })
+        .map(bucket_info_from_sdk)
+        .filter(|b| prefix.as_deref().is_none_or(|p| b.name.starts_with(p)))
         .collect();
 
-    Ok(buckets)
+    Ok(ListBucketsResult {
+        buckets,
+        continuation_token: result.continuation_token().map(|s| s.to_string()),
+    })
+}
+
+/// 补充单个 Bucket 的区域、版本控制、标签和公开状态，无权读取的项保持为 None
+async fn enrich_bucket(
+    config: &S3Config,
+    default_client: &aws_sdk_s3::Client,
+    regional_clients: &Mutex<HashMap<String, aws_sdk_s3::Client>>,
+    mut bucket: BucketInfo,
+) -> BucketInfo {
+    if bucket.region.is_none() {
+        bucket.region = get_bucket_region(default_client, &bucket.name).await.ok();
+    }
+
+    // AWS 上访问其他区域的 Bucket 需要使用对应区域的客户端
+    let client = match bucket.region.as_deref() {
+        Some(region)
+            if config.endpoint.is_none()
+                && config.region.as_deref().unwrap_or("us-east-1") != region =>
+        {
+            let mut clients = regional_clients.lock().await;
+            match clients.get(region) {
+                Some(client) => client.clone(),
+                None => {
+                    let mut regional = config.clone();
+                    regional.region = Some(region.to_string());
+                    match S3Client::new(regional).await {
+                        Ok(client) => {
+                            let client = client.client().clone();
+                            clients.insert(region.to_string(), client.clone());
+                            client
+                        }
+                        Err(_) => default_client.clone(),
+                    }
+                }
+            }
+        }
+        _ => default_client.clone(),
+    };
+
+    let (versioning, tags, is_public) = tokio::join!(
+        client.get_bucket_versioning().bucket(&bucket.name).send(),
+        super::tagging::load_bucket_tagging(&client, &bucket.name),
+        super::access::bucket_is_public(&client, &bucket.name),
+    );
+
+    bucket.versioning = versioning.ok().map(|v| {
+        v.status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_else(|| "Disabled".to_string())
+    });
+    bucket.tags = tags.ok();
+    bucket.is_public = is_public;
+    bucket
+}
+
+/// 并发补充 Buckets 的区域、版本控制、标签和公开状态
+///
+/// 每个 Bucket 完成后通过 `bucket-enriched` 事件推送，取消时返回已完成的部分。
+#[tauri::command]
+pub async fn enrich_buckets(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    config: S3Config,
+    buckets: Vec<BucketInfo>,
+    task_id: String,
+) -> Result<Vec<BucketInfo>, String> {
+    let client = S3Client::new(config.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    let task = tasks.register(&task_id);
+
+    let config = Arc::new(config);
+    let regional_clients = Arc::new(Mutex::new(HashMap::new()));
+    let semaphore = Arc::new(Semaphore::new(ENRICH_CONCURRENCY));
+
+    let mut workers = JoinSet::new();
+    for bucket in buckets {
+        let config = config.clone();
+        let s3 = client.client().clone();
+        let regional_clients = regional_clients.clone();
+        let semaphore = semaphore.clone();
+
+        workers.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            enrich_bucket(&config, &s3, &regional_clients, bucket).await
+        });
+    }
+
+    let mut enriched = Vec::new();
+    while let Some(joined) = workers.join_next().await {
+        if task.is_cancelled() {
+            workers.abort_all();
+            break;
+        }
+
+        let bucket = joined.map_err(|e| format!("Bucket enrichment task failed: {}", e))?;
+        let _ = app.emit(
+            "bucket-enriched",
+            &BucketEnriched {
+                task_id: task_id.clone(),
+                bucket: bucket.clone(),
+            },
+        );
+        enriched.push(bucket);
+    }
+
+    Ok(enriched)
 }
 
 /// 创建 Bucket
@@ -35,18 +196,20 @@ pub async fn create_bucket(
     config: S3Config,
     bucket_name: String,
     region: Option<String>,
+    object_lock_enabled: Option<bool>,
 ) -> Result<(), String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
 
+    // Object Lock 只能在创建 Bucket 时启用（同时会自动启用版本控制）
     let mut builder = client
         .client()
         .create_bucket()
-        .bucket(&bucket_name);
+        .bucket(&bucket_name)
+        .set_object_lock_enabled_for_bucket(object_lock_enabled);
 
     // 如果不是 us-east-1，需要指定 location constraint
     if let Some(ref r) = region {
         if r != "us-east-1" {
-            use aws_sdk_s3::types::CreateBucketConfiguration;
             let config = CreateBucketConfiguration::builder()
                 .location_constraint(r.as_str().into())
                 .build();
@@ -59,6 +222,249 @@ pub async fn create_bucket(
     Ok(())
 }
 
+/// 获取 Bucket 是否启用请求者付费
+#[tauri::command]
+pub async fn get_bucket_request_payment(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<bool, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = client
+        .client()
+        .get_bucket_request_payment()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get bucket request payment: {}", e))?;
+
+    Ok(result.payer() == Some(&Payer::Requester))
+}
+
+/// 设置 Bucket 是否启用请求者付费（仅 Bucket 所有者可设置）
+#[tauri::command]
+pub async fn put_bucket_request_payment(
+    config: S3Config,
+    bucket_name: String,
+    requester_pays: bool,
+) -> Result<(), String> {
+    let payer = if requester_pays {
+        Payer::Requester
+    } else {
+        Payer::BucketOwner
+    };
+    let configuration = RequestPaymentConfiguration::builder()
+        .payer(payer)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_request_payment()
+        .bucket(&bucket_name)
+        .request_payment_configuration(configuration)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket request payment: {}", e))?;
+
+    Ok(())
+}
+
+/// 按 S3 命名规则校验 Bucket 名称
+pub(crate) fn validate_bucket_name(name: &str) -> Result<(), String> {
+    const RESERVED_PREFIXES: &[&str] = &["xn--", "sthree-", "amzn-s3-demo-"];
+    const RESERVED_SUFFIXES: &[&str] = &["-s3alias", "--ol-s3", ".mrap", "--x-s3", "--table-s3"];
+
+    let invalid = |reason: &str| Err(format!("Invalid bucket name '{}': {}", name, reason));
+
+    if name.len() < 3 || name.len() > 63 {
+        return invalid("must be between 3 and 63 characters long");
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.' || c == '-')
+    {
+        return invalid("only lowercase letters, numbers, dots and hyphens are allowed");
+    }
+    let first = name.chars().next().unwrap_or('-');
+    let last = name.chars().last().unwrap_or('-');
+    if !first.is_ascii_alphanumeric() || !last.is_ascii_alphanumeric() {
+        return invalid("must begin and end with a letter or number");
+    }
+    if name.contains("..") {
+        return invalid("must not contain two adjacent periods");
+    }
+    if name.parse::<std::net::Ipv4Addr>().is_ok() {
+        return invalid("must not be formatted as an IP address");
+    }
+    if RESERVED_PREFIXES.iter().any(|p| name.starts_with(p)) {
+        return invalid("uses a reserved prefix");
+    }
+    if RESERVED_SUFFIXES.iter().any(|s| name.ends_with(s)) {
+        return invalid("uses a reserved suffix");
+    }
+
+    Ok(())
+}
+
+/// 公共预定义 ACL 需要同时关闭 BlockPublicAcls，否则新 Bucket 默认的公共访问阻止会拒绝该 ACL
+fn check_public_canned_acl(
+    canned_acl: Option<&BucketCannedAcl>,
+    public_access_block: Option<&PublicAccessBlockSettings>,
+) -> Result<(), String> {
+    let is_public = matches!(
+        canned_acl,
+        Some(
+            BucketCannedAcl::PublicRead
+                | BucketCannedAcl::PublicReadWrite
+                | BucketCannedAcl::AuthenticatedRead
+        )
+    );
+    if is_public && public_access_block.is_none_or(|b| b.block_public_acls) {
+        return Err(
+            "A public canned ACL requires a public access block with BlockPublicAcls disabled"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
+/// 使用完整选项创建 Bucket
+///
+/// 依次执行创建、版本控制、默认加密、标签、公共访问阻止和 ACL 设置，
+/// 任一步骤失败时删除已创建的 Bucket。
+#[tauri::command]
+pub async fn create_bucket_with_options(
+    config: S3Config,
+    bucket_name: String,
+    options: CreateBucketOptions,
+) -> Result<(), String> {
+    validate_bucket_name(&bucket_name)?;
+
+    // 在发送任何请求前校验所有选项
+    if options.object_lock_enabled && !options.versioning_enabled {
+        return Err("Object Lock requires versioning to be enabled".to_string());
+    }
+    let encryption = options
+        .encryption
+        .map(super::encryption::build_encryption_configuration)
+        .transpose()?;
+    let tagging = if options.tags.is_empty() {
+        None
+    } else {
+        Some(super::tagging::build_tagging(
+            &options.tags,
+            super::tagging::MAX_BUCKET_TAGS,
+        )?)
+    };
+    let public_access_block = options
+        .public_access_block
+        .as_ref()
+        .map(super::access::build_public_access_block);
+    let canned_acl = options
+        .canned_acl
+        .as_deref()
+        .map(super::access::parse_bucket_canned_acl)
+        .transpose()?;
+    check_public_canned_acl(canned_acl.as_ref(), options.public_access_block.as_ref())?;
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let s3 = client.client();
+
+    let mut builder = s3
+        .create_bucket()
+        .bucket(&bucket_name)
+        .object_lock_enabled_for_bucket(options.object_lock_enabled);
+
+    if let Some(r) = options.region.as_deref().filter(|r| *r != "us-east-1") {
+        builder = builder.create_bucket_configuration(
+            CreateBucketConfiguration::builder()
+                .location_constraint(r.into())
+                .build(),
+        );
+    }
+
+    // 新建 Bucket 默认禁用 ACL，需要先允许 ACL 才能设置预定义 ACL
+    if canned_acl.is_some() {
+        builder = builder.object_ownership(ObjectOwnership::BucketOwnerPreferred);
+    }
+
+    builder
+        .send()
+        .await
+        .map_err(|e| format!("Failed to create bucket: {}", e))?;
+
+    let configured: Result<(), String> = async {
+        // Object Lock 会自动启用版本控制
+        if options.versioning_enabled && !options.object_lock_enabled {
+            s3.put_bucket_versioning()
+                .bucket(&bucket_name)
+                .versioning_configuration(
+                    VersioningConfiguration::builder()
+                        .status(BucketVersioningStatus::Enabled)
+                        .build(),
+                )
+                .send()
+                .await
+                .map_err(|e| format!("Failed to enable versioning: {}", e))?;
+        }
+
+        if let Some(encryption) = encryption {
+            s3.put_bucket_encryption()
+                .bucket(&bucket_name)
+                .server_side_encryption_configuration(encryption)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to put bucket encryption: {}", e))?;
+        }
+
+        if let Some(tagging) = tagging {
+            s3.put_bucket_tagging()
+                .bucket(&bucket_name)
+                .tagging(tagging)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to put bucket tagging: {}", e))?;
+        }
+
+        if let Some(public_access_block) = public_access_block {
+            s3.put_public_access_block()
+                .bucket(&bucket_name)
+                .public_access_block_configuration(public_access_block)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to put public access block: {}", e))?;
+        }
+
+        if let Some(canned_acl) = canned_acl {
+            s3.put_bucket_acl()
+                .bucket(&bucket_name)
+                .acl(canned_acl)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to put bucket ACL: {}", e))?;
+        }
+
+        Ok(())
+    }
+    .await;
+
+    // 回滚：删除刚创建的 Bucket
+    if let Err(e) = configured {
+        return match s3.delete_bucket().bucket(&bucket_name).send().await {
+            Ok(_) => Err(format!("{} (bucket creation rolled back)", e)),
+            Err(rollback) => Err(format!(
+                "{} (rollback failed, bucket '{}' still exists: {})",
+                e, bucket_name, rollback
+            )),
+        };
+    }
+
+    Ok(())
+}
+
 /// 删除 Bucket
 #[tauri::command]
 pub async fn delete_bucket(config: S3Config, bucket_name: String) -> Result<(), String> {
@@ -75,6 +481,174 @@ pub async fn delete_bucket(config: S3Config, bucket_name: String) -> Result<(),
     Ok(())
 }
 
+/// 强制删除前要求输入完整的 Bucket 名称，大小写和空格都必须一致
+fn check_confirmation(bucket_name: &str, confirmation: &str) -> Result<(), String> {
+    if confirmation != bucket_name {
+        return Err("Confirmation does not match the bucket name".to_string());
+    }
+    Ok(())
+}
+
+/// 强制删除 Bucket
+///
+/// 先中止所有未完成的分片上传，再分批删除所有对象版本和删除标记，最后删除 Bucket。
+/// 进度通过 `force-delete-progress` 事件推送；取消或有对象删除失败时不会删除 Bucket。
+#[tauri::command]
+pub async fn force_delete_bucket(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    config: S3Config,
+    bucket_name: String,
+    confirmation: String,
+    task_id: String,
+) -> Result<BatchResult, String> {
+    check_confirmation(&bucket_name, &confirmation)?;
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let s3 = client.client();
+    let task = tasks.register(&task_id);
+
+    let mut progress = BatchProgress {
+        task_id: task_id.clone(),
+        processed: 0,
+        failed: 0,
+        current_key: None,
+    };
+    let mut result = BatchResult {
+        task_id,
+        succeeded: 0,
+        failures: Vec::new(),
+        cancelled: false,
+    };
+
+    // 1. 中止未完成的分片上传
+    let mut key_marker: Option<String> = None;
+    let mut upload_id_marker: Option<String> = None;
+    loop {
+        if task.is_cancelled() {
+            result.cancelled = true;
+            return Ok(result);
+        }
+
+        let page = s3
+            .list_multipart_uploads()
+            .bucket(&bucket_name)
+            .set_request_payer(client.config().request_payer(&bucket_name))
+            .set_key_marker(key_marker.take())
+            .set_upload_id_marker(upload_id_marker.take())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list multipart uploads: {}", e))?;
+
+        for upload in page.uploads() {
+            let (Some(key), Some(upload_id)) = (upload.key(), upload.upload_id()) else {
+                continue;
+            };
+            match s3
+                .abort_multipart_upload()
+                .bucket(&bucket_name)
+                .set_request_payer(client.config().request_payer(&bucket_name))
+                .key(key)
+                .upload_id(upload_id)
+                .send()
+                .await
+            {
+                Ok(_) => result.succeeded += 1,
+                Err(e) => {
+                    progress.failed += 1;
+                    result.failures.push(BatchFailure::from_sdk_error(key, &e));
+                }
+            }
+            progress.processed += 1;
+            progress.current_key = Some(key.to_string());
+        }
+        let _ = app.emit("force-delete-progress", &progress);
+
+        if !page.is_truncated().unwrap_or(false) {
+            break;
+        }
+        key_marker = page.next_key_marker().map(|s| s.to_string());
+        upload_id_marker = page.next_upload_id_marker().map(|s| s.to_string());
+    }
+
+    // 2. 分批删除所有对象版本和删除标记
+    let mut key_marker: Option<String> = None;
+    let mut version_id_marker: Option<String> = None;
+    loop {
+        if task.is_cancelled() {
+            result.cancelled = true;
+            return Ok(result);
+        }
+
+        let page = s3
+            .list_object_versions()
+            .bucket(&bucket_name)
+            .set_request_payer(client.config().request_payer(&bucket_name))
+            .set_key_marker(key_marker.take())
+            .set_version_id_marker(version_id_marker.take())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list object versions: {}", e))?;
+
+        let versions = page
+            .versions()
+            .iter()
+            .map(|v| (v.key(), v.version_id()))
+            .chain(
+                page.delete_markers()
+                    .iter()
+                    .map(|m| (m.key(), m.version_id())),
+            );
+
+        let mut objects = Vec::new();
+        for (key, version_id) in versions {
+            let Some(key) = key else { continue };
+            objects.push(
+                ObjectIdentifier::builder()
+                    .key(key)
+                    .set_version_id(version_id.map(|s| s.to_string()))
+                    .build()
+                    .map_err(|e| e.to_string())?,
+            );
+        }
+
+        for batch in objects.chunks(super::object::DELETE_BATCH_SIZE) {
+            progress.current_key = batch.last().map(|o| o.key().to_string());
+            let (succeeded, failures) = super::object::delete_object_batch(
+                s3,
+                &bucket_name,
+                client.config().request_payer(&bucket_name),
+                batch.to_vec(),
+            )
+            .await;
+            progress.processed += batch.len() as u64;
+            progress.failed += failures.len() as u64;
+            result.succeeded += succeeded;
+            result.failures.extend(failures);
+            let _ = app.emit("force-delete-progress", &progress);
+        }
+
+        if !page.is_truncated().unwrap_or(false) {
+            break;
+        }
+        key_marker = page.next_key_marker().map(|s| s.to_string());
+        version_id_marker = page.next_version_id_marker().map(|s| s.to_string());
+    }
+
+    if !result.failures.is_empty() {
+        return Ok(result);
+    }
+
+    // 3. 删除 Bucket
+    s3.delete_bucket()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete bucket: {}", e))?;
+
+    Ok(result)
+}
+
 /// 检查 Bucket 是否存在
 #[tauri::command]
 pub async fn head_bucket(config: S3Config, bucket_name: String) -> Result<bool, String> {
@@ -98,3 +672,77 @@ pub async fn head_bucket(config: S3Config, bucket_name: String) -> Result<bool,
         }
     }
 }
+
+/// 获取 Bucket 所在区域
+pub(crate) async fn get_bucket_region(
+    client: &aws_sdk_s3::Client,
+    bucket_name: &str,
+) -> Result<String, String> {
+    let location = client
+        .get_bucket_location()
+        .bucket(bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get bucket location: {}", e))?;
+
+    // us-east-1 的 location constraint 为空，EU 是 eu-west-1 的旧名称
+    Ok(match location.location_constraint().map(|c| c.as_str()) {
+        None | Some("") => "us-east-1".to_string(),
+        Some("EU") => "eu-west-1".to_string(),
+        Some(region) => region.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_bucket_name_accepts_valid_names() {
+        for name in ["abc", "my-bucket.logs", "0bucket9", &"a".repeat(63)] {
+            assert!(validate_bucket_name(name).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn validate_bucket_name_rejects_invalid_names() {
+        for name in [
+            "ab",
+            &"a".repeat(64),
+            "My-Bucket",
+            "bucket_name",
+            "-bucket",
+            "bucket.",
+            "my..bucket",
+            "192.168.1.1",
+            "xn--bucket",
+            "bucket-s3alias",
+            "bucket--ol-s3",
+        ] {
+            assert!(validate_bucket_name(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn public_canned_acl_requires_public_acls_allowed() {
+        let allow = PublicAccessBlockSettings::default();
+        let block = PublicAccessBlockSettings {
+            block_public_acls: true,
+            ..Default::default()
+        };
+
+        assert!(check_public_canned_acl(Some(&BucketCannedAcl::PublicRead), None).is_err());
+        assert!(check_public_canned_acl(Some(&BucketCannedAcl::PublicRead), Some(&block)).is_err());
+        assert!(check_public_canned_acl(Some(&BucketCannedAcl::PublicRead), Some(&allow)).is_ok());
+        assert!(check_public_canned_acl(Some(&BucketCannedAcl::Private), None).is_ok());
+        assert!(check_public_canned_acl(None, None).is_ok());
+    }
+
+    #[test]
+    fn force_delete_requires_exact_bucket_name() {
+        assert!(check_confirmation("my-bucket", "my-bucket").is_ok());
+        assert!(check_confirmation("my-bucket", "").is_err());
+        assert!(check_confirmation("my-bucket", "My-Bucket").is_err());
+        assert!(check_confirmation("my-bucket", " my-bucket").is_err());
+    }
+}
@@ -0,0 +1,423 @@
+use crate::s3::types::{
+    parse_expires, BatchFailure, ObjectMetadataUpdate, SseCustomerKey, SseOptions,
+};
+use aws_sdk_s3::operation::head_object::HeadObjectOutput;
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart, MetadataDirective, RequestPayer};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+// 超过该大小使用 UploadPartCopy 分片复制（CopyObject 最大支持 5GB）
+const MULTIPART_COPY_THRESHOLD: i64 = 1024 * 1024 * 1024;
+const COPY_PART_SIZE: i64 = 128 * 1024 * 1024;
+const MAX_PARTS: i64 = 10_000;
+const PART_COPY_CONCURRENCY: usize = 8;
+
+/// 复制参数
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CopyOptions {
+    /// 复制源对象的指定版本
+    pub source_version_id: Option<String>,
+    pub sse: Option<SseOptions>,
+    pub sse_c: Option<SseCustomerKey>,
+    pub source_sse_c: Option<SseCustomerKey>,
+    pub request_payer: Option<RequestPayer>,
+    /// 设置后使用 REPLACE 指令替换元数据，否则复制源对象的元数据
+    pub metadata: Option<ObjectMetadataUpdate>,
+    /// 调用方已读取的源对象属性，传入后不再重复 HeadObject
+    pub source_head: Option<HeadObjectOutput>,
+    /// 调用方已知的源对象大小（如列表结果），直接复制小对象时无需 HeadObject
+    pub source_size: Option<i64>,
+}
+
+/// 从 HeadObject 结果中取出可复制的元数据
+fn metadata_from_head(head: &HeadObjectOutput) -> ObjectMetadataUpdate {
+    ObjectMetadataUpdate {
+        content_type: head.content_type().map(|s| s.to_string()),
+        cache_control: head.cache_control().map(|s| s.to_string()),
+        content_disposition: head.content_disposition().map(|s| s.to_string()),
+        content_encoding: head.content_encoding().map(|s| s.to_string()),
+        content_language: head.content_language().map(|s| s.to_string()),
+        expires: head.expires_string().map(|s| s.to_string()),
+        metadata: head.metadata().cloned().unwrap_or_default(),
+    }
+}
+
+/// 按 RFC 3986 百分号编码，`keep_slash` 为 true 时保留路径分隔符
+pub(crate) fn percent_encode(value: &str, keep_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(b as char)
+            }
+            b'/' if keep_slash => encoded.push('/'),
+            _ => encoded.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    encoded
+}
+
+/// 构造 x-amz-copy-source，key 需要 URL 编码（空格、+、# 和中文等）
+pub(crate) fn copy_source(bucket: &str, key: &str, version_id: Option<&str>) -> String {
+    let source = format!("{}/{}", bucket, percent_encode(key, true));
+    match version_id {
+        Some(version_id) => format!("{}?versionId={}", source, percent_encode(version_id, false)),
+        None => source,
+    }
+}
+
+/// 读取源对象属性
+async fn head_source(
+    client: &aws_sdk_s3::Client,
+    source_bucket: &str,
+    source_key: &str,
+    options: &CopyOptions,
+) -> Result<HeadObjectOutput, BatchFailure> {
+    let mut builder = client
+        .head_object()
+        .bucket(source_bucket)
+        .key(source_key)
+        .set_version_id(options.source_version_id.clone())
+        .set_request_payer(options.request_payer.clone());
+    if let Some(k) = &options.source_sse_c {
+        builder = builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(&k.key)
+            .sse_customer_key_md5(&k.key_md5);
+    }
+    builder
+        .send()
+        .await
+        .map_err(|e| BatchFailure::from_sdk_error(source_key, &e))
+}
+
+/// 复制对象，小对象使用 CopyObject，大对象使用并发 UploadPartCopy
+///
+/// 分片复制时会手动带上源对象的元数据、存储类型和标签，与 CopyObject 的默认行为一致。
+/// 替换元数据时同样保留存储类型和标签。只有调用方未提供所需的源对象属性时才会 HeadObject。
+pub(crate) async fn copy_object_with(
+    client: &aws_sdk_s3::Client,
+    source_bucket: &str,
+    source_key: &str,
+    dest_bucket: &str,
+    dest_key: &str,
+    options: &CopyOptions,
+) -> Result<(), BatchFailure> {
+    let known_size = options
+        .source_head
+        .as_ref()
+        .and_then(|h| h.content_length())
+        .or(options.source_size);
+    // 替换元数据和分片复制需要源对象的存储类型和元数据
+    let needs_head = options.source_head.is_none()
+        && (options.metadata.is_some()
+            || known_size.is_none_or(|size| size > MULTIPART_COPY_THRESHOLD));
+    let fetched = if needs_head {
+        Some(head_source(client, source_bucket, source_key, options).await?)
+    } else {
+        None
+    };
+    let head = options.source_head.as_ref().or(fetched.as_ref());
+    let size = head
+        .and_then(|h| h.content_length())
+        .or(known_size)
+        .unwrap_or(0);
+    let storage_class = head.and_then(|h| h.storage_class()).cloned();
+    let to_failure = |message: String| BatchFailure {
+        key: source_key.to_string(),
+        code: None,
+        message,
+    };
+
+    let source = copy_source(
+        source_bucket,
+        source_key,
+        options.source_version_id.as_deref(),
+    );
+
+    if size <= MULTIPART_COPY_THRESHOLD {
+        let mut builder = client
+            .copy_object()
+            .bucket(dest_bucket)
+            .copy_source(&source)
+            .key(dest_key)
+            .set_request_payer(options.request_payer.clone());
+
+        // REPLACE 时未指定存储类型会变为 STANDARD，需要显式保留
+        if let Some(metadata) = &options.metadata {
+            builder = builder
+                .metadata_directive(MetadataDirective::Replace)
+                .set_content_type(metadata.content_type.clone())
+                .set_cache_control(metadata.cache_control.clone())
+                .set_content_disposition(metadata.content_disposition.clone())
+                .set_content_encoding(metadata.content_encoding.clone())
+                .set_content_language(metadata.content_language.clone())
+                .set_expires(metadata.validate().map_err(to_failure)?)
+                .set_metadata(Some(metadata.encoded_metadata()))
+                .set_storage_class(storage_class);
+        }
+
+        if let Some(sse) = &options.sse {
+            builder = builder
+                .server_side_encryption(sse.server_side_encryption().map_err(to_failure)?)
+                .set_ssekms_key_id(sse.kms_key_id.clone())
+                .set_bucket_key_enabled(sse.bucket_key_enabled);
+        }
+        if let Some(k) = &options.sse_c {
+            builder = builder
+                .sse_customer_algorithm(k.algorithm)
+                .sse_customer_key(&k.key)
+                .sse_customer_key_md5(&k.key_md5);
+        }
+        if let Some(k) = &options.source_sse_c {
+            builder = builder
+                .copy_source_sse_customer_algorithm(k.algorithm)
+                .copy_source_sse_customer_key(&k.key)
+                .copy_source_sse_customer_key_md5(&k.key_md5);
+        }
+
+        builder
+            .send()
+            .await
+            .map_err(|e| BatchFailure::from_sdk_error(source_key, &e))?;
+        return Ok(());
+    }
+
+    // 分片复制不会自动复制标签，读取失败（无权限）时忽略
+    let tagging = client
+        .get_object_tagging()
+        .bucket(source_bucket)
+        .key(source_key)
+        .set_version_id(options.source_version_id.clone())
+        .set_request_payer(options.request_payer.clone())
+        .send()
+        .await
+        .ok()
+        .map(|t| {
+            t.tag_set()
+                .iter()
+                .map(|tag| {
+                    format!(
+                        "{}={}",
+                        percent_encode(tag.key(), false),
+                        percent_encode(tag.value(), false)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("&")
+        })
+        .filter(|t| !t.is_empty());
+
+    let metadata = match &options.metadata {
+        Some(metadata) => metadata.clone(),
+        None => head.map(metadata_from_head).unwrap_or_default(),
+    };
+    // 源对象中无法解析的 Expires 直接忽略
+    let expires = match &options.metadata {
+        Some(metadata) => metadata.validate().map_err(to_failure)?,
+        None => metadata
+            .expires
+            .as_deref()
+            .and_then(|e| parse_expires(e).ok()),
+    };
+    let user_metadata = metadata.encoded_metadata();
+
+    let mut create_builder = client
+        .create_multipart_upload()
+        .bucket(dest_bucket)
+        .key(dest_key)
+        .set_content_type(metadata.content_type)
+        .set_cache_control(metadata.cache_control)
+        .set_content_disposition(metadata.content_disposition)
+        .set_content_encoding(metadata.content_encoding)
+        .set_content_language(metadata.content_language)
+        .set_expires(expires)
+        .set_metadata(Some(user_metadata))
+        .set_storage_class(storage_class)
+        .set_tagging(tagging)
+        .set_request_payer(options.request_payer.clone());
+
+    if let Some(sse) = &options.sse {
+        create_builder = create_builder
+            .server_side_encryption(sse.server_side_encryption().map_err(to_failure)?)
+            .set_ssekms_key_id(sse.kms_key_id.clone())
+            .set_bucket_key_enabled(sse.bucket_key_enabled);
+    }
+    if let Some(k) = &options.sse_c {
+        create_builder = create_builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(&k.key)
+            .sse_customer_key_md5(&k.key_md5);
+    }
+
+    let upload_id = create_builder
+        .send()
+        .await
+        .map_err(|e| BatchFailure::from_sdk_error(source_key, &e))?
+        .upload_id()
+        .ok_or_else(|| to_failure("Failed to get upload id".to_string()))?
+        .to_string();
+
+    let result = copy_parts(
+        client,
+        &source,
+        source_key,
+        dest_bucket,
+        dest_key,
+        &upload_id,
+        size,
+        options,
+    )
+    .await;
+
+    // 任一分片失败时放弃上传，避免残留未完成的分片
+    if result.is_err() {
+        let _ = client
+            .abort_multipart_upload()
+            .bucket(dest_bucket)
+            .key(dest_key)
+            .upload_id(&upload_id)
+            .set_request_payer(options.request_payer.clone())
+            .send()
+            .await;
+    }
+    result
+}
+
+/// 并发执行 UploadPartCopy 并完成分片上传
+#[allow(clippy::too_many_arguments)]
+async fn copy_parts(
+    client: &aws_sdk_s3::Client,
+    source: &str,
+    source_key: &str,
+    dest_bucket: &str,
+    dest_key: &str,
+    upload_id: &str,
+    size: i64,
+    options: &CopyOptions,
+) -> Result<(), BatchFailure> {
+    // 分片数不能超过 10000
+    let part_size = COPY_PART_SIZE.max((size + MAX_PARTS - 1) / MAX_PARTS);
+    let semaphore = Arc::new(Semaphore::new(PART_COPY_CONCURRENCY));
+
+    let mut workers = JoinSet::new();
+    let mut position = 0;
+    let mut part_number = 1;
+    while position < size {
+        let end = (position + part_size).min(size) - 1;
+
+        let mut builder = client
+            .upload_part_copy()
+            .bucket(dest_bucket)
+            .key(dest_key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .copy_source(source)
+            .copy_source_range(format!("bytes={}-{}", position, end))
+            .set_request_payer(options.request_payer.clone());
+        if let Some(k) = &options.sse_c {
+            builder = builder
+                .sse_customer_algorithm(k.algorithm)
+                .sse_customer_key(&k.key)
+                .sse_customer_key_md5(&k.key_md5);
+        }
+        if let Some(k) = &options.source_sse_c {
+            builder = builder
+                .copy_source_sse_customer_algorithm(k.algorithm)
+                .copy_source_sse_customer_key(&k.key)
+                .copy_source_sse_customer_key_md5(&k.key_md5);
+        }
+
+        let semaphore = semaphore.clone();
+        workers.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            builder.send().await.map(|r| {
+                CompletedPart::builder()
+                    .part_number(part_number)
+                    .set_e_tag(
+                        r.copy_part_result()
+                            .and_then(|p| p.e_tag())
+                            .map(|s| s.to_string()),
+                    )
+                    .build()
+            })
+        });
+
+        part_number += 1;
+        position = end + 1;
+    }
+
+    let mut completed_parts = Vec::new();
+    while let Some(joined) = workers.join_next().await {
+        let part = joined
+            .map_err(|e| BatchFailure {
+                key: source_key.to_string(),
+                code: None,
+                message: format!("Part copy task failed: {}", e),
+            })?
+            .map_err(|e| BatchFailure::from_sdk_error(source_key, &e))?;
+        completed_parts.push(part);
+    }
+    completed_parts.sort_by_key(|p| p.part_number());
+
+    client
+        .complete_multipart_upload()
+        .bucket(dest_bucket)
+        .key(dest_key)
+        .upload_id(upload_id)
+        .multipart_upload(
+            CompletedMultipartUpload::builder()
+                .set_parts(Some(completed_parts))
+                .build(),
+        )
+        .set_request_payer(options.request_payer.clone())
+        .send()
+        .await
+        .map_err(|e| BatchFailure::from_sdk_error(source_key, &e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_encode_reserved_characters() {
+        assert_eq!(percent_encode("a-b_c.d~e", false), "a-b_c.d~e");
+        assert_eq!(percent_encode("a b+c#d", false), "a%20b%2Bc%23d");
+        assert_eq!(percent_encode("dir/file", true), "dir/file");
+        assert_eq!(percent_encode("dir/file", false), "dir%2Ffile");
+        assert_eq!(percent_encode("文件", true), "%E6%96%87%E4%BB%B6");
+    }
+
+    #[test]
+    fn copy_source_encodes_key_and_version() {
+        assert_eq!(
+            copy_source("bucket", "photos/2024 summer/a+b.jpg", None),
+            "bucket/photos/2024%20summer/a%2Bb.jpg"
+        );
+        assert_eq!(
+            copy_source("bucket", "a.txt", Some("3/L4kqtJl+cm8=")),
+            "bucket/a.txt?versionId=3%2FL4kqtJl%2Bcm8%3D"
+        );
+    }
+
+    #[test]
+    fn metadata_from_head_copies_system_and_user_metadata() {
+        let head = HeadObjectOutput::builder()
+            .content_type("image/png")
+            .cache_control("max-age=60")
+            .metadata("owner", "alice")
+            .build();
+        let metadata = metadata_from_head(&head);
+        assert_eq!(metadata.content_type.as_deref(), Some("image/png"));
+        assert_eq!(metadata.cache_control.as_deref(), Some("max-age=60"));
+        assert_eq!(metadata.content_encoding, None);
+        assert_eq!(
+            metadata.metadata.get("owner").map(|s| s.as_str()),
+            Some("alice")
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::s3::types::S3Config;
+use crate::s3::types::{S3Config, SseCustomerKey};
 use crate::s3::client::S3Client;
 
 /// 下载单个文件
@@ -8,14 +8,26 @@ pub async fn download_file(
     bucket: String,
     key: String,
     save_path: String,
+    sse_customer_key: Option<String>,
 ) -> Result<String, String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
 
-    let result = client
+    let mut builder = client
         .client()
         .get_object()
         .bucket(&bucket)
         .key(&key)
+        .set_request_payer(client.config().request_payer(&bucket));
+
+    if let Some(k) = sse_c {
+        builder = builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(k.key)
+            .sse_customer_key_md5(k.key_md5);
+    }
+
+    let result = builder
         .send()
         .await
         .map_err(|e| format!("Failed to download file: {}", e))?;
@@ -51,7 +63,7 @@ pub async fn download_files(
     let mut downloaded_paths = Vec::new();
 
     for (key, save_path) in files {
-        match download_file(config.clone(), bucket.clone(), key, save_path).await {
+        match download_file(config.clone(), bucket.clone(), key, save_path, None).await {
             Ok(p) => downloaded_paths.push(p),
             Err(e) => {
                 return Err(format!("Download failed for one file: {}", e));
@@ -68,14 +80,26 @@ pub async fn get_file_content(
     config: S3Config,
     bucket: String,
     key: String,
+    sse_customer_key: Option<String>,
 ) -> Result<String, String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
 
-    let result = client
+    let mut builder = client
         .client()
         .get_object()
         .bucket(&bucket)
         .key(&key)
+        .set_request_payer(client.config().request_payer(&bucket));
+
+    if let Some(k) = sse_c {
+        builder = builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(k.key)
+            .sse_customer_key_md5(k.key_md5);
+    }
+
+    let result = builder
         .send()
         .await
         .map_err(|e| format!("Failed to get file content: {}", e))?;
@@ -98,14 +122,26 @@ pub async fn get_file_bytes(
     config: S3Config,
     bucket: String,
     key: String,
+    sse_customer_key: Option<String>,
 ) -> Result<String, String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
 
-    let result = client
+    let mut builder = client
         .client()
         .get_object()
         .bucket(&bucket)
         .key(&key)
+        .set_request_payer(client.config().request_payer(&bucket));
+
+    if let Some(k) = sse_c {
+        builder = builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(k.key)
+            .sse_customer_key_md5(k.key_md5);
+    }
+
+    let result = builder
         .send()
         .await
         .map_err(|e| format!("Failed to get file bytes: {}", e))?;
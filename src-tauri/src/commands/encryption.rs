@@ -0,0 +1,155 @@
+use crate::s3::types::{
+    is_unsupported_error, parse_sse_algorithm, BucketEncryption, S3Config,
+};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::types::{
+    ServerSideEncryptionByDefault, ServerSideEncryptionConfiguration, ServerSideEncryptionRule,
+};
+
+/// 读取 Bucket 默认加密配置，未配置时返回 None
+pub(crate) async fn load_bucket_encryption(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Option<BucketEncryption>, String> {
+    let result = match client.get_bucket_encryption().bucket(bucket).send().await {
+        Ok(result) => result,
+        Err(e)
+            if e.code() == Some("ServerSideEncryptionConfigurationNotFoundError")
+                || is_unsupported_error(e.code()) =>
+        {
+            return Ok(None);
+        }
+        Err(e) => return Err(format!("Failed to get bucket encryption: {}", e)),
+    };
+
+    let rule = result
+        .server_side_encryption_configuration()
+        .and_then(|c| c.rules().first());
+
+    Ok(rule.and_then(|rule| {
+        rule.apply_server_side_encryption_by_default()
+            .map(|default| BucketEncryption {
+                algorithm: default.sse_algorithm().as_str().to_string(),
+                kms_key_id: default.kms_master_key_id().map(|s| s.to_string()),
+                bucket_key_enabled: rule.bucket_key_enabled().unwrap_or(false),
+            })
+    }))
+}
+
+/// 获取 Bucket 默认加密配置，未配置时返回 None
+#[tauri::command]
+pub async fn get_bucket_encryption(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<Option<BucketEncryption>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    load_bucket_encryption(client.client(), &bucket_name).await
+}
+
+/// 校验 BucketEncryption 并转换为 SDK 类型
+pub(crate) fn build_encryption_configuration(
+    encryption: BucketEncryption,
+) -> Result<ServerSideEncryptionConfiguration, String> {
+    let sse = parse_sse_algorithm(&encryption.algorithm, encryption.kms_key_id.is_some())?;
+
+    let default = ServerSideEncryptionByDefault::builder()
+        .sse_algorithm(sse)
+        .set_kms_master_key_id(encryption.kms_key_id)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let rule = ServerSideEncryptionRule::builder()
+        .apply_server_side_encryption_by_default(default)
+        .bucket_key_enabled(encryption.bucket_key_enabled)
+        .build();
+
+    ServerSideEncryptionConfiguration::builder()
+        .rules(rule)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// 设置 Bucket 默认加密配置
+#[tauri::command]
+pub async fn put_bucket_encryption(
+    config: S3Config,
+    bucket_name: String,
+    encryption: BucketEncryption,
+) -> Result<(), String> {
+    let configuration = build_encryption_configuration(encryption)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_encryption()
+        .bucket(&bucket_name)
+        .server_side_encryption_configuration(configuration)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket encryption: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除 Bucket 默认加密配置
+#[tauri::command]
+pub async fn delete_bucket_encryption(config: S3Config, bucket_name: String) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .delete_bucket_encryption()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete bucket encryption: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_s3::types::ServerSideEncryption;
+
+    fn encryption(algorithm: &str, kms_key_id: Option<&str>) -> BucketEncryption {
+        BucketEncryption {
+            algorithm: algorithm.to_string(),
+            kms_key_id: kms_key_id.map(|s| s.to_string()),
+            bucket_key_enabled: true,
+        }
+    }
+
+    #[test]
+    fn parse_sse_algorithm_checks_kms_key() {
+        assert_eq!(
+            parse_sse_algorithm("AES256", false).unwrap(),
+            ServerSideEncryption::Aes256
+        );
+        assert_eq!(
+            parse_sse_algorithm("aws:kms", true).unwrap(),
+            ServerSideEncryption::AwsKms
+        );
+        assert_eq!(
+            parse_sse_algorithm("aws:kms:dsse", true).unwrap(),
+            ServerSideEncryption::AwsKmsDsse
+        );
+        assert!(parse_sse_algorithm("AES256", true).is_err());
+        assert!(parse_sse_algorithm("aes256", false).is_err());
+    }
+
+    #[test]
+    fn build_encryption_configuration_sets_default_rule() {
+        let configuration =
+            build_encryption_configuration(encryption("aws:kms", Some("alias/s3"))).unwrap();
+        let rule = &configuration.rules()[0];
+        let default = rule.apply_server_side_encryption_by_default().unwrap();
+        assert_eq!(*default.sse_algorithm(), ServerSideEncryption::AwsKms);
+        assert_eq!(default.kms_master_key_id(), Some("alias/s3"));
+        assert_eq!(rule.bucket_key_enabled(), Some(true));
+
+        assert!(build_encryption_configuration(encryption("AES256", Some("alias/s3"))).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use crate::s3::types::{IntelligentTieringConfigInfo, S3Config};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::types::{
+    IntelligentTieringAccessTier, IntelligentTieringAndOperator, IntelligentTieringConfiguration,
+    IntelligentTieringFilter, IntelligentTieringStatus, Tag, Tiering,
+};
+
+// 各归档层允许的天数范围
+const ARCHIVE_ACCESS_DAYS: (i32, i32) = (90, 730);
+const DEEP_ARCHIVE_ACCESS_DAYS: (i32, i32) = (180, 730);
+
+fn tiering_from_sdk(
+    configuration: &IntelligentTieringConfiguration,
+) -> IntelligentTieringConfigInfo {
+    let filter = configuration.filter();
+    let and = filter.and_then(|f| f.and());
+    let days = |tier: IntelligentTieringAccessTier| {
+        configuration
+            .tierings()
+            .iter()
+            .find(|t| *t.access_tier() == tier)
+            .map(|t| t.days())
+    };
+
+    IntelligentTieringConfigInfo {
+        id: configuration.id().to_string(),
+        enabled: *configuration.status() == IntelligentTieringStatus::Enabled,
+        prefix: and
+            .and_then(|a| a.prefix())
+            .or_else(|| filter.and_then(|f| f.prefix()))
+            .filter(|p| !p.is_empty())
+            .map(|s| s.to_string()),
+        tags: match and {
+            Some(and) => super::tagging::to_tag_infos(and.tags()),
+            None => filter
+                .and_then(|f| f.tag())
+                .map(|tag| super::tagging::to_tag_infos(std::slice::from_ref(tag)))
+                .unwrap_or_default(),
+        },
+        archive_access_days: days(IntelligentTieringAccessTier::ArchiveAccess),
+        deep_archive_access_days: days(IntelligentTieringAccessTier::DeepArchiveAccess),
+    }
+}
+
+fn build_tiering(
+    tier: IntelligentTieringAccessTier,
+    days: i32,
+    (min, max): (i32, i32),
+) -> Result<Tiering, String> {
+    if days < min || days > max {
+        return Err(format!(
+            "{} days must be between {} and {}",
+            tier.as_str(),
+            min,
+            max
+        ));
+    }
+
+    Tiering::builder()
+        .access_tier(tier)
+        .days(days)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// 校验 IntelligentTieringConfigInfo 并转换为 SDK 类型
+fn tiering_to_sdk(
+    configuration: IntelligentTieringConfigInfo,
+) -> Result<IntelligentTieringConfiguration, String> {
+    if configuration.id.trim().is_empty() {
+        return Err("Configuration ID is required".to_string());
+    }
+
+    let mut tierings = Vec::new();
+    if let Some(days) = configuration.archive_access_days {
+        tierings.push(build_tiering(
+            IntelligentTieringAccessTier::ArchiveAccess,
+            days,
+            ARCHIVE_ACCESS_DAYS,
+        )?);
+    }
+    if let Some(days) = configuration.deep_archive_access_days {
+        if configuration.archive_access_days.is_some_and(|a| days <= a) {
+            return Err(
+                "Deep Archive Access days must be greater than Archive Access days".to_string(),
+            );
+        }
+        tierings.push(build_tiering(
+            IntelligentTieringAccessTier::DeepArchiveAccess,
+            days,
+            DEEP_ARCHIVE_ACCESS_DAYS,
+        )?);
+    }
+    if tierings.is_empty() {
+        return Err("At least one archive tier is required".to_string());
+    }
+
+    let tags = configuration
+        .tags
+        .iter()
+        .map(|t| {
+            Tag::builder()
+                .key(&t.key)
+                .value(&t.value)
+                .build()
+                .map_err(|e| e.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // 多个过滤条件时必须使用 And 组合，没有条件时应用于整个 Bucket
+    let filter = if tags.len() + usize::from(configuration.prefix.is_some()) > 1 {
+        Some(
+            IntelligentTieringFilter::builder()
+                .and(
+                    IntelligentTieringAndOperator::builder()
+                        .set_prefix(configuration.prefix)
+                        .set_tags(Some(tags))
+                        .build(),
+                )
+                .build(),
+        )
+    } else if let Some(tag) = tags.into_iter().next() {
+        Some(IntelligentTieringFilter::builder().tag(tag).build())
+    } else {
+        configuration
+            .prefix
+            .map(|prefix| IntelligentTieringFilter::builder().prefix(prefix).build())
+    };
+
+    IntelligentTieringConfiguration::builder()
+        .id(configuration.id)
+        .set_filter(filter)
+        .status(if configuration.enabled {
+            IntelligentTieringStatus::Enabled
+        } else {
+            IntelligentTieringStatus::Disabled
+        })
+        .set_tierings(Some(tierings))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// 列出 Bucket 的智能分层归档配置
+#[tauri::command]
+pub async fn list_intelligent_tiering_configurations(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<Vec<IntelligentTieringConfigInfo>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let mut configurations = Vec::new();
+    let mut continuation_token: Option<String> = None;
+
+    loop {
+        let page = client
+            .client()
+            .list_bucket_intelligent_tiering_configurations()
+            .bucket(&bucket_name)
+            .set_continuation_token(continuation_token.take())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list intelligent-tiering configurations: {}", e))?;
+
+        configurations.extend(
+            page.intelligent_tiering_configuration_list()
+                .iter()
+                .map(tiering_from_sdk),
+        );
+
+        match page.next_continuation_token() {
+            Some(token) if page.is_truncated().unwrap_or(false) => {
+                continuation_token = Some(token.to_string());
+            }
+            _ => break,
+        }
+    }
+
+    Ok(configurations)
+}
+
+/// 创建或覆盖智能分层归档配置
+#[tauri::command]
+pub async fn put_intelligent_tiering_configuration(
+    config: S3Config,
+    bucket_name: String,
+    configuration: IntelligentTieringConfigInfo,
+) -> Result<(), String> {
+    let id = configuration.id.clone();
+    let configuration = tiering_to_sdk(configuration)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_intelligent_tiering_configuration()
+        .bucket(&bucket_name)
+        .id(id)
+        .intelligent_tiering_configuration(configuration)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put intelligent-tiering configuration: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除智能分层归档配置
+#[tauri::command]
+pub async fn delete_intelligent_tiering_configuration(
+    config: S3Config,
+    bucket_name: String,
+    id: String,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .delete_bucket_intelligent_tiering_configuration()
+        .bucket(&bucket_name)
+        .id(id)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete intelligent-tiering configuration: {}", e))?;
+
+    Ok(())
+}
@@ -0,0 +1,448 @@
+use crate::s3::types::{
+    AccessLogCount, AccessLogStats, AccessLogTotals, BatchFailure, BucketLoggingConfig, S3Config,
+};
+use crate::s3::client::S3Client;
+use crate::s3::TaskManager;
+use aws_sdk_s3::types::{
+    BucketLoggingStatus, LoggingEnabled, PartitionDateSource, PartitionedPrefix, SimplePrefix,
+    TargetObjectKeyFormat,
+};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::Emitter;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+// 默认返回的 key 和请求者数量
+const DEFAULT_TOP_N: usize = 20;
+
+// 并发读取日志对象的数量
+const LOG_READ_CONCURRENCY: usize = 8;
+
+// 日志记录中的时间格式，例如 [06/Feb/2019:00:00:38 +0000]
+const LOG_TIME_FORMAT: &str = "%d/%b/%Y:%H:%M:%S %z";
+
+// 日志对象名中的投递时间格式，例如 2019-02-06-00-05-12-UNIQUEID
+const LOG_KEY_TIME_FORMAT: &str = "%Y-%m-%d-%H-%M-%S";
+
+/// 一条访问日志中参与汇总的字段
+struct AccessLogRecord {
+    bucket: String,
+    time: DateTime<Utc>,
+    requester: String,
+    operation: String,
+    key: Option<String>,
+    status: String,
+    bytes_sent: u64,
+}
+
+/// 按空格拆分日志行，方括号和双引号内的内容作为一个字段
+fn split_log_fields(line: &str) -> Vec<&str> {
+    let mut fields = Vec::new();
+    let mut rest = line.trim_start();
+
+    while !rest.is_empty() {
+        let (field, remaining) = match rest.as_bytes()[0] {
+            b'[' => match rest.find(']') {
+                Some(end) => (&rest[1..end], &rest[end + 1..]),
+                None => (&rest[1..], ""),
+            },
+            b'"' => match rest[1..].find('"') {
+                Some(end) => (&rest[1..end + 1], &rest[end + 2..]),
+                None => (&rest[1..], ""),
+            },
+            _ => match rest.find(' ') {
+                Some(end) => (&rest[..end], &rest[end..]),
+                None => (rest, ""),
+            },
+        };
+        fields.push(field);
+        rest = remaining.trim_start();
+    }
+
+    fields
+}
+
+/// 解析一行 S3 服务器访问日志
+fn parse_log_line(line: &str) -> Option<AccessLogRecord> {
+    let fields = split_log_fields(line);
+    if fields.len() < 12 {
+        return None;
+    }
+
+    let time = DateTime::parse_from_str(fields[2], LOG_TIME_FORMAT)
+        .ok()?
+        .with_timezone(&Utc);
+
+    Some(AccessLogRecord {
+        bucket: fields[1].to_string(),
+        time,
+        requester: fields[4].to_string(),
+        operation: fields[6].to_string(),
+        key: Some(fields[7]).filter(|k| *k != "-").map(|k| k.to_string()),
+        status: fields[9].to_string(),
+        bytes_sent: fields[11].parse().unwrap_or(0),
+    })
+}
+
+/// 从日志对象名中解析投递时间，简单格式和分区格式的文件名均以时间开头
+fn log_object_time(key: &str) -> Option<DateTime<Utc>> {
+    let name = key.rsplit('/').next()?;
+    let time = name.get(..19)?;
+    NaiveDateTime::parse_from_str(time, LOG_KEY_TIME_FORMAT)
+        .ok()
+        .map(|t| t.and_utc())
+}
+
+fn parse_time(time: Option<&str>) -> Result<Option<DateTime<Utc>>, String> {
+    time.map(|t| {
+        DateTime::parse_from_rfc3339(t)
+            .map(|t| t.with_timezone(&Utc))
+            .map_err(|e| format!("Invalid time '{}': {}", t, e))
+    })
+    .transpose()
+}
+
+fn add_to_totals(totals: &mut AccessLogTotals, bytes_sent: u64) {
+    totals.requests += 1;
+    totals.bytes_sent += bytes_sent;
+}
+
+/// 按请求数取前 n 项
+fn top_counts(counts: &HashMap<String, AccessLogTotals>, n: usize) -> Vec<AccessLogCount> {
+    let mut top: Vec<AccessLogCount> = counts
+        .iter()
+        .map(|(name, totals)| AccessLogCount {
+            name: name.clone(),
+            requests: totals.requests,
+            bytes_sent: totals.bytes_sent,
+        })
+        .collect();
+    top.sort_by(|a, b| {
+        b.requests
+            .cmp(&a.requests)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    top.truncate(n);
+    top
+}
+
+/// 读取 Bucket 访问日志配置，未启用时返回 None
+async fn load_bucket_logging(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Option<BucketLoggingConfig>, String> {
+    let result = client
+        .get_bucket_logging()
+        .bucket(bucket)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get bucket logging: {}", e))?;
+
+    Ok(result.logging_enabled().map(|l| BucketLoggingConfig {
+        target_bucket: l.target_bucket().to_string(),
+        target_prefix: l.target_prefix().to_string(),
+        partitioned_prefix: l
+            .target_object_key_format()
+            .is_some_and(|f| f.partitioned_prefix().is_some()),
+    }))
+}
+
+/// 获取 Bucket 服务器访问日志配置，未启用时返回 None
+#[tauri::command]
+pub async fn get_bucket_logging(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<Option<BucketLoggingConfig>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    load_bucket_logging(client.client(), &bucket_name).await
+}
+
+/// 设置 Bucket 服务器访问日志配置，`logging` 为 None 时关闭访问日志
+#[tauri::command]
+pub async fn put_bucket_logging(
+    config: S3Config,
+    bucket_name: String,
+    logging: Option<BucketLoggingConfig>,
+) -> Result<(), String> {
+    let status = match logging {
+        Some(logging) => {
+            if logging.target_bucket.trim().is_empty() {
+                return Err("Target bucket is required".to_string());
+            }
+
+            let key_format = if logging.partitioned_prefix {
+                TargetObjectKeyFormat::builder()
+                    .partitioned_prefix(
+                        PartitionedPrefix::builder()
+                            .partition_date_source(PartitionDateSource::EventTime)
+                            .build(),
+                    )
+                    .build()
+            } else {
+                TargetObjectKeyFormat::builder()
+                    .simple_prefix(SimplePrefix::builder().build())
+                    .build()
+            };
+
+            BucketLoggingStatus::builder()
+                .logging_enabled(
+                    LoggingEnabled::builder()
+                        .target_bucket(logging.target_bucket)
+                        .target_prefix(logging.target_prefix)
+                        .target_object_key_format(key_format)
+                        .build()
+                        .map_err(|e| e.to_string())?,
+                )
+                .build()
+        }
+        None => BucketLoggingStatus::builder().build(),
+    };
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_logging()
+        .bucket(&bucket_name)
+        .bucket_logging_status(status)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket logging: {}", e))?;
+
+    Ok(())
+}
+
+/// 分析 Bucket 的服务器访问日志
+///
+/// 默认从 Bucket 的日志配置中读取目标 Bucket 和前缀，也可以通过 `log_bucket`
+/// 和 `log_prefix` 指定。`start` 和 `end` 为 RFC 3339 格式的时间范围。
+/// 每页日志对象处理完成后通过 `access-log-progress` 事件推送部分结果。
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn analyze_access_logs(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    config: S3Config,
+    bucket_name: String,
+    task_id: String,
+    start: Option<String>,
+    end: Option<String>,
+    log_bucket: Option<String>,
+    log_prefix: Option<String>,
+    top_n: Option<usize>,
+) -> Result<AccessLogStats, String> {
+    let start_time = parse_time(start.as_deref())?;
+    let end_time = parse_time(end.as_deref())?;
+    let top_n = top_n.unwrap_or(DEFAULT_TOP_N);
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let (log_bucket, log_prefix) = match log_bucket {
+        Some(log_bucket) => (log_bucket, log_prefix.unwrap_or_default()),
+        None => {
+            let logging = load_bucket_logging(client.client(), &bucket_name)
+                .await?
+                .ok_or("Access logging is not enabled for this bucket")?;
+            (
+                logging.target_bucket,
+                log_prefix.unwrap_or(logging.target_prefix),
+            )
+        }
+    };
+
+    let task = tasks.register(&task_id);
+    let semaphore = Arc::new(Semaphore::new(LOG_READ_CONCURRENCY));
+
+    let mut stats = AccessLogStats {
+        task_id,
+        bucket: bucket_name,
+        log_bucket,
+        log_prefix,
+        start,
+        end,
+        log_objects_scanned: 0,
+        failed_objects: Vec::new(),
+        total_requests: 0,
+        total_bytes_sent: 0,
+        parse_errors: 0,
+        by_operation: HashMap::new(),
+        by_status: HashMap::new(),
+        top_keys: Vec::new(),
+        top_requesters: Vec::new(),
+        completed: false,
+    };
+    let mut by_key: HashMap<String, AccessLogTotals> = HashMap::new();
+    let mut by_requester: HashMap<String, AccessLogTotals> = HashMap::new();
+    let mut continuation_token: Option<String> = None;
+
+    loop {
+        if task.is_cancelled() {
+            break;
+        }
+
+        let page = client
+            .client()
+            .list_objects_v2()
+            .bucket(&stats.log_bucket)
+            .set_request_payer(client.config().request_payer(&stats.log_bucket))
+            .prefix(&stats.log_prefix)
+            .set_continuation_token(continuation_token.take())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list log objects: {}", e))?;
+
+        let mut readers = JoinSet::new();
+        for key in page.contents().iter().filter_map(|o| o.key()) {
+            // 日志对象的投递时间晚于其中所有记录，早于起始时间的对象可以跳过
+            if let (Some(start), Some(delivered)) = (start_time, log_object_time(key)) {
+                if delivered < start {
+                    continue;
+                }
+            }
+
+            let s3 = client.client().clone();
+            let log_bucket = stats.log_bucket.clone();
+            let request_payer = client.config().request_payer(&log_bucket);
+            let key = key.to_string();
+            let semaphore = semaphore.clone();
+
+            readers.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let object = s3
+                    .get_object()
+                    .bucket(&log_bucket)
+                    .set_request_payer(request_payer)
+                    .key(&key)
+                    .send()
+                    .await
+                    .map_err(|e| BatchFailure::from_sdk_error(&key, &e))?;
+                let body = object
+                    .body
+                    .collect()
+                    .await
+                    .map_err(|e| BatchFailure {
+                        key: key.clone(),
+                        code: None,
+                        message: format!("Failed to read log object: {}", e),
+                    })?
+                    .into_bytes();
+
+                Ok::<_, BatchFailure>(String::from_utf8_lossy(&body).into_owned())
+            });
+        }
+
+        while let Some(joined) = readers.join_next().await {
+            // 单个日志对象读取失败时记录下来，继续分析其余对象
+            let content = match joined.map_err(|e| format!("Log reader task failed: {}", e))? {
+                Ok(content) => content,
+                Err(failure) => {
+                    stats.failed_objects.push(failure);
+                    continue;
+                }
+            };
+            stats.log_objects_scanned += 1;
+
+            for line in content.lines().filter(|l| !l.trim().is_empty()) {
+                let Some(record) = parse_log_line(line) else {
+                    stats.parse_errors += 1;
+                    continue;
+                };
+
+                // 多个 Bucket 可能共用同一个日志目标
+                if record.bucket != stats.bucket
+                    || start_time.is_some_and(|s| record.time < s)
+                    || end_time.is_some_and(|e| record.time > e)
+                {
+                    continue;
+                }
+
+                stats.total_requests += 1;
+                stats.total_bytes_sent += record.bytes_sent;
+                add_to_totals(
+                    stats.by_operation.entry(record.operation).or_default(),
+                    record.bytes_sent,
+                );
+                add_to_totals(
+                    stats.by_status.entry(record.status).or_default(),
+                    record.bytes_sent,
+                );
+                add_to_totals(
+                    by_requester.entry(record.requester).or_default(),
+                    record.bytes_sent,
+                );
+                if let Some(key) = record.key {
+                    add_to_totals(by_key.entry(key).or_default(), record.bytes_sent);
+                }
+            }
+        }
+
+        stats.top_keys = top_counts(&by_key, top_n);
+        stats.top_requesters = top_counts(&by_requester, top_n);
+        let _ = app.emit("access-log-progress", &stats);
+
+        match page.next_continuation_token() {
+            Some(token) if page.is_truncated().unwrap_or(false) => {
+                continuation_token = Some(token.to_string());
+            }
+            _ => {
+                stats.completed = true;
+                break;
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // AWS 文档中的日志示例
+    const SAMPLE: &str = r#"79a59df900b949e55d96a1e698fbacedfd6e09d98eacf8f8d5218e7cd47ef2be awsexamplebucket1 [06/Feb/2019:00:00:38 +0000] 192.0.2.3 79a59df900b949e55d96a1e698fbacedfd6e09d98eacf8f8d5218e7cd47ef2be 3E57427F3EXAMPLE REST.GET.VERSIONING - "GET /awsexamplebucket1?versioning HTTP/1.1" 200 - 113 - 7 - "-" "S3Console/0.4" - s9lzHYrFp76ZVxRcpX9+5cjAnEH2ROuNkd2BHfIa6UkFVdtjf5mKR3/eTPFvsiP/XV/VLi31234= SigV2 ECDHE-RSA-AES128-GCM-SHA256 AuthHeader awsexamplebucket1.s3.us-west-1.amazonaws.com TLSV1.2 arn:aws:s3:us-west-1:123456789012:accesspoint/example-AP Yes"#;
+
+    #[test]
+    fn split_keeps_bracketed_and_quoted_fields() {
+        let fields = split_log_fields(SAMPLE);
+        assert_eq!(fields[2], "06/Feb/2019:00:00:38 +0000");
+        assert_eq!(fields[8], "GET /awsexamplebucket1?versioning HTTP/1.1");
+        assert_eq!(fields[15], "-");
+        assert_eq!(fields[16], "S3Console/0.4");
+        assert_eq!(fields.len(), 26);
+    }
+
+    #[test]
+    fn split_handles_unterminated_fields() {
+        assert_eq!(split_log_fields("a [b c"), vec!["a", "b c"]);
+        assert_eq!(split_log_fields("  a \"b c"), vec!["a", "b c"]);
+        assert!(split_log_fields("   ").is_empty());
+    }
+
+    #[test]
+    fn parse_sample_line() {
+        let record = parse_log_line(SAMPLE).unwrap();
+        assert_eq!(record.bucket, "awsexamplebucket1");
+        assert_eq!(record.time.to_rfc3339(), "2019-02-06T00:00:38+00:00");
+        assert_eq!(record.operation, "REST.GET.VERSIONING");
+        assert_eq!(record.key, None);
+        assert_eq!(record.status, "200");
+        assert_eq!(record.bytes_sent, 113);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_lines() {
+        assert!(parse_log_line("owner bucket [not a time] ip").is_none());
+        let bad_time = SAMPLE.replace("06/Feb/2019", "2019-02-06");
+        assert!(parse_log_line(&bad_time).is_none());
+    }
+
+    #[test]
+    fn parse_log_object_names() {
+        let time = log_object_time("logs/2019-02-06-00-05-12-UNIQUEID").unwrap();
+        assert_eq!(time.to_rfc3339(), "2019-02-06T00:05:12+00:00");
+        assert!(log_object_time("logs/unexpected").is_none());
+    }
+}
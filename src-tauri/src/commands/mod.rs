@@ -4,6 +4,22 @@ mod upload;
 mod download;
 mod multipart;
 mod security;
+mod tagging;
+mod encryption;
+mod object_lock;
+mod access;
+mod website;
+mod task;
+mod usage;
+mod blueprint;
+mod notification;
+mod replication;
+mod logging;
+mod intelligent_tiering;
+mod transfer;
+mod copy;
+mod presign;
+mod share;
 
 pub use bucket::*;
 pub use object::*;
@@ -11,6 +27,21 @@ pub use upload::*;
 pub use download::*;
 pub use multipart::*;
 pub use security::*;
+pub use tagging::*;
+pub use encryption::*;
+pub use object_lock::*;
+pub use access::*;
+pub use website::*;
+pub use task::*;
+pub use usage::*;
+pub use blueprint::*;
+pub use notification::*;
+pub use replication::*;
+pub use logging::*;
+pub use intelligent_tiering::*;
+pub use transfer::*;
+pub use presign::*;
+pub use share::*;
 
 // 导出 types 给 commands 使用
 pub use crate::s3::types::S3Config;
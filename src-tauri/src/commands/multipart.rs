@@ -1,4 +1,4 @@
-use crate::s3::types::S3Config;
+use crate::s3::types::{S3Config, SseCustomerKey, SseOptions};
 use crate::s3::client::S3Client;
 use aws_sdk_s3::types::CompletedMultipartUpload;
 use aws_sdk_s3::types::CompletedPart;
@@ -7,12 +7,16 @@ use tokio::fs::File;
 
 /// 分片上传
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn upload_multipart(
     config: S3Config,
     bucket: String,
     key: String,
     file_path: String,
     part_size_mb: Option<usize>,
+    sse: Option<SseOptions>,
+    sse_customer_key: Option<String>,
+    acl: Option<String>,
 ) -> Result<String, String> {
     let client = S3Client::new(config.clone()).await.map_err(|e| e.to_string())?;
 
@@ -27,15 +31,51 @@ pub async fn upload_multipart(
 
     // 如果文件小于 5MB，直接使用普通上传
     if file_size < 5 * 1024 * 1024 {
-        return super::upload::upload_file(config.clone(), bucket, key, file_path, None).await;
+        return super::upload::upload_file(
+            config.clone(),
+            bucket,
+            key,
+            file_path,
+            None,
+            sse,
+            sse_customer_key,
+            acl,
+        )
+        .await;
+    }
+
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
+    if sse.is_some() && sse_c.is_some() {
+        return Err("SSE options and SSE-C key cannot be used together".to_string());
     }
 
     // 1. 创建分片上传
-    let upload_id = client
+    let mut create_builder = client
         .client()
         .create_multipart_upload()
         .bucket(&bucket)
-        .key(&key)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key);
+
+    if let Some(sse) = sse {
+        create_builder = create_builder
+            .server_side_encryption(sse.server_side_encryption()?)
+            .set_ssekms_key_id(sse.kms_key_id)
+            .set_bucket_key_enabled(sse.bucket_key_enabled);
+    }
+
+    if let Some(acl) = acl {
+        create_builder = create_builder.acl(super::access::parse_object_canned_acl(&acl)?);
+    }
+
+    if let Some(k) = &sse_c {
+        create_builder = create_builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(&k.key)
+            .sse_customer_key_md5(&k.key_md5);
+    }
+
+    let upload_id = create_builder
         .send()
         .await
         .map_err(|e| format!("Failed to create multipart upload: {}", e))?
@@ -62,15 +102,25 @@ pub async fn upload_multipart(
             .await
             .map_err(|e| format!("Failed to read file chunk: {}", e))?;
 
-        // 上传分片
-        let upload_result = client
+        // 上传分片（SSE-C 需要每个分片都携带密钥）
+        let mut part_builder = client
             .client()
             .upload_part()
             .bucket(&bucket)
+            .set_request_payer(client.config().request_payer(&bucket))
             .key(&key)
             .upload_id(&upload_id)
             .part_number(part_number as i32)
-            .body(aws_sdk_s3::primitives::ByteStream::from(buffer))
+            .body(aws_sdk_s3::primitives::ByteStream::from(buffer));
+
+        if let Some(k) = &sse_c {
+            part_builder = part_builder
+                .sse_customer_algorithm(k.algorithm)
+                .sse_customer_key(&k.key)
+                .sse_customer_key_md5(&k.key_md5);
+        }
+
+        let upload_result = part_builder
             .send()
             .await
             .map_err(|e| format!("Failed to upload part {}: {}", part_number, e))?;
@@ -100,6 +150,7 @@ pub async fn upload_multipart(
         .client()
         .complete_multipart_upload()
         .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
         .key(&key)
         .upload_id(&upload_id)
         .multipart_upload(completed_upload)
@@ -124,6 +175,7 @@ pub async fn abort_multipart_upload(
         .client()
         .abort_multipart_upload()
         .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
         .key(&key)
         .upload_id(&upload_id)
         .send()
@@ -0,0 +1,421 @@
+use crate::s3::types::{NotificationConfig, NotificationRuleInfo, S3Config};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::types::{
+    Event, EventBridgeConfiguration, FilterRule, FilterRuleName, LambdaFunctionConfiguration,
+    NotificationConfiguration, NotificationConfigurationFilter, QueueConfiguration, S3KeyFilter,
+    TopicConfiguration,
+};
+
+// 通知目标类型
+const TARGET_QUEUE: &str = "queue";
+const TARGET_TOPIC: &str = "topic";
+const TARGET_LAMBDA: &str = "lambda";
+
+// MinIO 支持但 AWS 不支持的事件
+const MINIO_EVENTS: &[&str] = &[
+    "s3:ObjectAccessed:*",
+    "s3:ObjectAccessed:Get",
+    "s3:ObjectAccessed:GetRetention",
+    "s3:ObjectAccessed:GetLegalHold",
+    "s3:ObjectAccessed:Head",
+    "s3:ObjectAccessed:Attributes",
+    "s3:ObjectCreated:PutRetention",
+    "s3:ObjectCreated:PutLegalHold",
+    "s3:ObjectCreated:PutTagging",
+    "s3:ObjectCreated:DeleteTagging",
+    "s3:ObjectRemoved:NoOP",
+    "s3:ObjectTransition:*",
+    "s3:ObjectTransition:Failed",
+    "s3:ObjectTransition:Complete",
+    "s3:ObjectManyVersions",
+    "s3:ObjectLargeVersions",
+    "s3:PrefixManyFolders",
+    "s3:Scanner:ManyVersions",
+    "s3:Scanner:BigPrefix",
+    "s3:BucketCreated",
+    "s3:BucketRemoved",
+];
+
+fn parse_event(event: &str) -> Result<Event, String> {
+    if Event::values().contains(&event) || MINIO_EVENTS.contains(&event) {
+        Ok(Event::from(event))
+    } else {
+        Err(format!("Unsupported notification event: {}", event))
+    }
+}
+
+/// 检查 ARN 的服务与目标类型是否匹配，例如 arn:minio:sqs::_:webhook 只能作为 queue
+fn validate_target(target_type: &str, arn: &str) -> Result<(), String> {
+    let parts: Vec<&str> = arn.split(':').collect();
+    if parts.len() < 6 || parts[0] != "arn" {
+        return Err(format!("Invalid target ARN: {}", arn));
+    }
+
+    let expected = match target_type {
+        TARGET_QUEUE => "sqs",
+        TARGET_TOPIC => "sns",
+        TARGET_LAMBDA => "lambda",
+        other => return Err(format!("Unsupported notification target type: {}", other)),
+    };
+    if parts[2] != expected {
+        return Err(format!(
+            "ARN {} is not a {} target (expected service '{}')",
+            arn, target_type, expected
+        ));
+    }
+
+    Ok(())
+}
+
+fn filter_to_sdk(
+    prefix: Option<String>,
+    suffix: Option<String>,
+) -> Option<NotificationConfigurationFilter> {
+    let mut rules = Vec::new();
+    if let Some(prefix) = prefix.filter(|p| !p.is_empty()) {
+        rules.push(
+            FilterRule::builder()
+                .name(FilterRuleName::Prefix)
+                .value(prefix)
+                .build(),
+        );
+    }
+    if let Some(suffix) = suffix.filter(|s| !s.is_empty()) {
+        rules.push(
+            FilterRule::builder()
+                .name(FilterRuleName::Suffix)
+                .value(suffix)
+                .build(),
+        );
+    }
+
+    if rules.is_empty() {
+        return None;
+    }
+
+    Some(
+        NotificationConfigurationFilter::builder()
+            .key(S3KeyFilter::builder().set_filter_rules(Some(rules)).build())
+            .build(),
+    )
+}
+
+/// 从过滤器中取出 prefix 和 suffix（服务端返回的规则名大小写不固定）
+fn filter_from_sdk(
+    filter: Option<&NotificationConfigurationFilter>,
+) -> (Option<String>, Option<String>) {
+    let rules = filter
+        .and_then(|f| f.key())
+        .map(|k| k.filter_rules())
+        .unwrap_or_default();
+    let find = |name: &str| {
+        rules
+            .iter()
+            .find(|r| {
+                r.name()
+                    .is_some_and(|n| n.as_str().eq_ignore_ascii_case(name))
+            })
+            .and_then(|r| r.value())
+            .map(|s| s.to_string())
+    };
+
+    (find("prefix"), find("suffix"))
+}
+
+fn notification_from_sdk(
+    topics: &[TopicConfiguration],
+    queues: &[QueueConfiguration],
+    lambdas: &[LambdaFunctionConfiguration],
+    event_bridge: Option<&EventBridgeConfiguration>,
+) -> NotificationConfig {
+    let rule = |target_type: &str, id: Option<&str>, arn: &str, events: &[Event], filter| {
+        let (prefix, suffix) = filter_from_sdk(filter);
+        NotificationRuleInfo {
+            id: id.map(|s| s.to_string()),
+            target_type: target_type.to_string(),
+            target_arn: arn.to_string(),
+            events: events.iter().map(|e| e.as_str().to_string()).collect(),
+            prefix,
+            suffix,
+        }
+    };
+
+    let mut rules = Vec::new();
+    for q in queues {
+        rules.push(rule(
+            TARGET_QUEUE,
+            q.id(),
+            q.queue_arn(),
+            q.events(),
+            q.filter(),
+        ));
+    }
+    for t in topics {
+        rules.push(rule(
+            TARGET_TOPIC,
+            t.id(),
+            t.topic_arn(),
+            t.events(),
+            t.filter(),
+        ));
+    }
+    for l in lambdas {
+        rules.push(rule(
+            TARGET_LAMBDA,
+            l.id(),
+            l.lambda_function_arn(),
+            l.events(),
+            l.filter(),
+        ));
+    }
+
+    NotificationConfig {
+        rules,
+        event_bridge_enabled: event_bridge.is_some(),
+    }
+}
+
+/// 校验 NotificationConfig 并转换为 SDK 类型
+fn notification_to_sdk(
+    notification: NotificationConfig,
+) -> Result<NotificationConfiguration, String> {
+    let mut builder = NotificationConfiguration::builder();
+
+    for rule in notification.rules {
+        validate_target(&rule.target_type, &rule.target_arn)?;
+        if rule.events.is_empty() {
+            return Err(format!("No events selected for target {}", rule.target_arn));
+        }
+        let events = rule
+            .events
+            .iter()
+            .map(|e| parse_event(e))
+            .collect::<Result<Vec<_>, _>>()?;
+        let filter = filter_to_sdk(rule.prefix, rule.suffix);
+
+        builder = match rule.target_type.as_str() {
+            TARGET_QUEUE => builder.queue_configurations(
+                QueueConfiguration::builder()
+                    .set_id(rule.id)
+                    .queue_arn(rule.target_arn)
+                    .set_events(Some(events))
+                    .set_filter(filter)
+                    .build()
+                    .map_err(|e| e.to_string())?,
+            ),
+            TARGET_TOPIC => builder.topic_configurations(
+                TopicConfiguration::builder()
+                    .set_id(rule.id)
+                    .topic_arn(rule.target_arn)
+                    .set_events(Some(events))
+                    .set_filter(filter)
+                    .build()
+                    .map_err(|e| e.to_string())?,
+            ),
+            _ => builder.lambda_function_configurations(
+                LambdaFunctionConfiguration::builder()
+                    .set_id(rule.id)
+                    .lambda_function_arn(rule.target_arn)
+                    .set_events(Some(events))
+                    .set_filter(filter)
+                    .build()
+                    .map_err(|e| e.to_string())?,
+            ),
+        };
+    }
+
+    if notification.event_bridge_enabled {
+        builder = builder.event_bridge_configuration(EventBridgeConfiguration::builder().build());
+    }
+
+    Ok(builder.build())
+}
+
+/// 获取 Bucket 事件通知配置
+#[tauri::command]
+pub async fn get_bucket_notification(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<NotificationConfig, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = client
+        .client()
+        .get_bucket_notification_configuration()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get bucket notification: {}", e))?;
+
+    Ok(notification_from_sdk(
+        result.topic_configurations(),
+        result.queue_configurations(),
+        result.lambda_function_configurations(),
+        result.event_bridge_configuration(),
+    ))
+}
+
+/// 设置 Bucket 事件通知配置（覆盖原有配置）
+///
+/// `skip_destination_validation` 为 true 时服务端不会向目标发送测试消息。
+#[tauri::command]
+pub async fn put_bucket_notification(
+    config: S3Config,
+    bucket_name: String,
+    notification: NotificationConfig,
+    skip_destination_validation: Option<bool>,
+) -> Result<(), String> {
+    let configuration = notification_to_sdk(notification)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_notification_configuration()
+        .bucket(&bucket_name)
+        .notification_configuration(configuration)
+        .set_skip_destination_validation(skip_destination_validation)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket notification: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除 Bucket 全部事件通知配置
+#[tauri::command]
+pub async fn delete_bucket_notification(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    // S3 没有删除接口，写入空配置即可清除
+    client
+        .client()
+        .put_bucket_notification_configuration()
+        .bucket(&bucket_name)
+        .notification_configuration(NotificationConfiguration::builder().build())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete bucket notification: {}", e))?;
+
+    Ok(())
+}
+
+/// 获取支持的事件名称（包括 MinIO 扩展事件）
+#[tauri::command]
+pub async fn get_notification_event_names() -> Result<Vec<String>, String> {
+    Ok(Event::values()
+        .iter()
+        .chain(MINIO_EVENTS)
+        .map(|e| e.to_string())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(target_type: &str, arn: &str, events: &[&str]) -> NotificationRuleInfo {
+        NotificationRuleInfo {
+            id: None,
+            target_type: target_type.to_string(),
+            target_arn: arn.to_string(),
+            events: events.iter().map(|e| e.to_string()).collect(),
+            prefix: Some("images/".to_string()),
+            suffix: Some(String::new()),
+        }
+    }
+
+    #[test]
+    fn parse_events() {
+        assert_eq!(
+            parse_event("s3:ObjectCreated:*"),
+            Ok(Event::S3ObjectCreated)
+        );
+        assert!(parse_event("s3:ObjectAccessed:Get").is_ok());
+        assert!(parse_event("s3:ObjectCreated").is_err());
+    }
+
+    #[test]
+    fn validate_target_service() {
+        assert!(validate_target(TARGET_QUEUE, "arn:aws:sqs:us-east-1:123456789012:queue").is_ok());
+        assert!(validate_target(TARGET_QUEUE, "arn:minio:sqs::_:webhook").is_ok());
+        assert!(validate_target(TARGET_TOPIC, "arn:aws:sqs:us-east-1:123456789012:queue").is_err());
+        assert!(validate_target(TARGET_LAMBDA, "arn:aws:lambda:us-east-1").is_err());
+        assert!(validate_target("webhook", "arn:minio:sqs::_:webhook").is_err());
+    }
+
+    #[test]
+    fn filter_round_trip() {
+        assert!(filter_to_sdk(None, Some(String::new())).is_none());
+
+        let filter = filter_to_sdk(Some("logs/".to_string()), Some(".gz".to_string()));
+        assert_eq!(
+            filter_from_sdk(filter.as_ref()),
+            (Some("logs/".to_string()), Some(".gz".to_string()))
+        );
+    }
+
+    #[test]
+    fn filter_names_are_case_insensitive() {
+        let filter = NotificationConfigurationFilter::builder()
+            .key(
+                S3KeyFilter::builder()
+                    .filter_rules(
+                        FilterRule::builder()
+                            .name(FilterRuleName::from("Prefix"))
+                            .value("a/")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            filter_from_sdk(Some(&filter)),
+            (Some("a/".to_string()), None)
+        );
+    }
+
+    #[test]
+    fn notification_round_trip() {
+        let config = NotificationConfig {
+            rules: vec![
+                rule(
+                    TARGET_QUEUE,
+                    "arn:minio:sqs::_:webhook",
+                    &["s3:ObjectCreated:*"],
+                ),
+                rule(
+                    TARGET_LAMBDA,
+                    "arn:aws:lambda:us-east-1:123456789012:function:thumb",
+                    &["s3:ObjectRemoved:Delete"],
+                ),
+            ],
+            event_bridge_enabled: true,
+        };
+        let sdk = notification_to_sdk(config).unwrap();
+        let back = notification_from_sdk(
+            sdk.topic_configurations(),
+            sdk.queue_configurations(),
+            sdk.lambda_function_configurations(),
+            sdk.event_bridge_configuration(),
+        );
+
+        assert!(back.event_bridge_enabled);
+        assert_eq!(back.rules.len(), 2);
+        assert_eq!(back.rules[0].target_type, TARGET_QUEUE);
+        assert_eq!(back.rules[0].prefix.as_deref(), Some("images/"));
+        assert_eq!(back.rules[0].suffix, None);
+        assert_eq!(back.rules[1].events, vec!["s3:ObjectRemoved:Delete"]);
+    }
+
+    #[test]
+    fn notification_requires_events() {
+        let config = NotificationConfig {
+            rules: vec![rule(TARGET_QUEUE, "arn:minio:sqs::_:webhook", &[])],
+            event_bridge_enabled: false,
+        };
+        assert!(notification_to_sdk(config).is_err());
+    }
+}
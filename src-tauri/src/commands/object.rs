@@ -1,7 +1,120 @@
-use crate::s3::types::{ListObjectsResult, ObjectMetadata, S3Config};
+use crate::s3::types::{
+    format_timestamp, parse_expires, BatchFailure, BatchProgress, BatchResult, ListObjectsResult,
+    ObjectDetails, ObjectMetadata, ObjectMetadataUpdate, S3Config, SseCustomerKey, SseOptions,
+};
 use crate::s3::client::S3Client;
+use crate::s3::TaskManager;
+use aws_sdk_s3::error::SdkError;
+use aws_sdk_s3::operation::delete_objects::DeleteObjectsError;
+use aws_sdk_s3::operation::head_object::{HeadObjectError, HeadObjectOutput};
+use aws_sdk_s3::types::{
+    ChecksumMode, ChecksumType, Delete, ObjectIdentifier, ObjectLockLegalHoldStatus, RequestPayer,
+};
+use std::sync::Arc;
+use tauri::Emitter;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// 单个 DeleteObjects 请求最多包含的 key 数量
+pub(crate) const DELETE_BATCH_SIZE: usize = 1000;
+
+// 同时进行的 DeleteObjects 请求数
+const DELETE_CONCURRENCY: usize = 4;
+
+// 前缀复制/移动时的并发数
+const COPY_CONCURRENCY: usize = 8;
+
+/// 发送一次 DeleteObjects 请求，返回删除失败的 key
+async fn send_delete_objects(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+    request_payer: Option<RequestPayer>,
+    objects: Vec<ObjectIdentifier>,
+) -> Result<Vec<BatchFailure>, SdkError<DeleteObjectsError>> {
+    let delete = Delete::builder()
+        .set_objects(Some(objects))
+        .quiet(true)
+        .build()
+        .map_err(SdkError::construction_failure)?;
+
+    let result = client
+        .delete_objects()
+        .bucket(bucket)
+        .set_request_payer(request_payer)
+        .delete(delete)
+        .send()
+        .await?;
+
+    Ok(result
+        .errors()
+        .iter()
+        .map(|e| BatchFailure {
+            key: e.key().unwrap_or("").to_string(),
+            code: e.code().map(|c| c.to_string()),
+            message: e.message().unwrap_or("").to_string(),
+        })
+        .collect())
+}
+
+/// 发送一次 DeleteObjects 请求，返回成功数量和失败列表
+///
+/// 请求本身失败时，批次中的所有 key 都记为失败。
+pub(crate) async fn delete_object_batch(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+    request_payer: Option<RequestPayer>,
+    objects: Vec<ObjectIdentifier>,
+) -> (u64, Vec<BatchFailure>) {
+    let total = objects.len() as u64;
+    let keys: Vec<String> = objects.iter().map(|o| o.key().to_string()).collect();
+
+    match send_delete_objects(client, bucket, request_payer, objects).await {
+        Ok(failures) => (total - failures.len() as u64, failures),
+        Err(e) => {
+            let failures = keys
+                .into_iter()
+                .map(|key| BatchFailure::from_sdk_error(key, &e))
+                .collect();
+            (0, failures)
+        }
+    }
+}
+
+/// 目录条目，来自公共前缀或目录标记对象
+fn folder_entry(key: &str, last_modified: String) -> ObjectMetadata {
+    ObjectMetadata {
+        key: key.to_string(),
+        last_modified,
+        size: 0,
+        etag: String::new(),
+        storage_class: String::new(),
+        content_type: None,
+        is_folder: true,
+        tag_count: None,
+        server_side_encryption: None,
+        sse_kms_key_id: None,
+        replication_status: None,
+    }
+}
+
+/// 目录前缀补上结尾的 /，避免 `logs` 匹配到 `logs-archive/` 等同名前缀的对象
+fn folder_prefix(prefix: &str) -> String {
+    if prefix.is_empty() || prefix.ends_with('/') {
+        prefix.to_string()
+    } else {
+        format!("{}/", prefix)
+    }
+}
+
+/// 以 / 结尾的空对象是目录标记，有内容的同名对象仍按文件处理
+fn is_folder_marker(key: &str, size: i64) -> bool {
+    key.ends_with('/') && size == 0
+}
 
 /// 列出 Objects
+///
+/// 公共前缀和目录标记对象合并为 `is_folder` 目录条目，所有条目按 key 排序，
+/// 与 S3 返回的顺序一致，翻页时不会打乱顺序。
 #[tauri::command]
 pub async fn list_objects(
     config: S3Config,
@@ -17,7 +130,8 @@ pub async fn list_objects(
         .client()
         .list_objects_v2()
         .bucket(&bucket)
-        .set_prefix(prefix);
+        .set_prefix(prefix.clone())
+        .set_request_payer(client.config().request_payer(&bucket));
 
     if let Some(d) = delimiter {
         builder = builder.delimiter(d);
@@ -36,14 +150,27 @@ pub async fn list_objects(
         .await
         .map_err(|e| format!("Failed to list objects: {}", e))?;
 
-    let objects = result
-        .contents()
-        .iter()
-        .map(|obj| ObjectMetadata {
-            key: obj.key().unwrap_or("").to_string(),
+    // 当前目录自身的标记不显示
+    let mut objects: Vec<ObjectMetadata> = Vec::new();
+    for obj in result.contents() {
+        let key = obj.key().unwrap_or("");
+        if is_folder_marker(key, obj.size().unwrap_or(0)) {
+            if Some(key) != prefix.as_deref() {
+                objects.push(folder_entry(
+                    key,
+                    obj.last_modified()
+                        .map(format_timestamp)
+                        .unwrap_or_default(),
+                ));
+            }
+            continue;
+        }
+
+        objects.push(ObjectMetadata {
+            key: key.to_string(),
             last_modified: obj
                 .last_modified()
-                .map(|d| format!("{:?}", d))
+                .map(format_timestamp)
                 .unwrap_or_default(),
             size: obj.size().unwrap_or(0) as i64,
             etag: obj.e_tag().unwrap_or("").to_string(),
@@ -53,15 +180,30 @@ pub async fn list_objects(
                 .unwrap_or_default(),
             content_type: None,
             is_folder: false,
-        })
-        .collect();
+            tag_count: None,
+            server_side_encryption: None,
+            sse_kms_key_id: None,
+            replication_status: None,
+        });
+    }
 
-    let common_prefixes = result
+    let common_prefixes: Vec<String> = result
         .common_prefixes()
         .iter()
         .filter_map(|p| p.prefix().map(|s| s.to_string()))
         .collect();
 
+    // 公共前缀与目录标记合并为同一个目录
+    for common_prefix in &common_prefixes {
+        if !objects
+            .iter()
+            .any(|o| o.is_folder && &o.key == common_prefix)
+        {
+            objects.push(folder_entry(common_prefix, String::new()));
+        }
+    }
+    objects.sort_by(|a, b| a.key.cmp(&b.key));
+
     Ok(ListObjectsResult {
         objects,
         common_prefixes,
@@ -70,6 +212,39 @@ pub async fn list_objects(
     })
 }
 
+/// 创建目录，写入以 / 结尾的空标记对象，返回目录 key
+#[tauri::command]
+pub async fn create_folder(
+    config: S3Config,
+    bucket: String,
+    key: String,
+) -> Result<String, String> {
+    // 只去掉首尾的 /，空格是 key 的一部分
+    let name = key.trim_matches('/');
+    if name.is_empty() {
+        return Err("Folder name must not be empty".to_string());
+    }
+    if name.split('/').any(|part| part.is_empty()) {
+        return Err("Folder name must not contain empty path segments".to_string());
+    }
+    let key = format!("{}/", name);
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_object()
+        .bucket(&bucket)
+        .key(&key)
+        .content_length(0)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to create folder: {}", e))?;
+
+    Ok(key)
+}
+
 /// 删除 Object
 #[tauri::command]
 pub async fn delete_object(
@@ -83,6 +258,7 @@ pub async fn delete_object(
         .client()
         .delete_object()
         .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
         .key(&key)
         .send()
         .await
@@ -92,66 +268,248 @@ pub async fn delete_object(
 }
 
 /// 批量删除 Objects
+///
+/// 超过 1000 个 key 时拆分为多个 DeleteObjects 请求，返回删除失败的 key。
+/// 某个请求本身失败时返回错误，之前批次中已删除的对象不会恢复。
 #[tauri::command]
 pub async fn delete_objects(
     config: S3Config,
     bucket: String,
     keys: Vec<String>,
 ) -> Result<Vec<String>, String> {
-    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
-
-    use aws_sdk_s3::types::ObjectIdentifier;
-
-    let delete_objects: Vec<ObjectIdentifier> = keys
+    let delete_objects = keys
         .iter()
-        .map(|key| ObjectIdentifier::builder().key(key).build().unwrap())
-        .collect();
-
-    let result = client
-        .client()
-        .delete_objects()
-        .bucket(&bucket)
-        .delete(
-            aws_sdk_s3::types::Delete::builder()
-                .set_objects(Some(delete_objects))
+        .map(|key| {
+            ObjectIdentifier::builder()
+                .key(key)
                 .build()
-                .map_err(|e| e.to_string())?,
+                .map_err(|e| e.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let mut errors = Vec::new();
+    for batch in delete_objects.chunks(DELETE_BATCH_SIZE) {
+        let failures = send_delete_objects(
+            client.client(),
+            &bucket,
+            client.config().request_payer(&bucket),
+            batch.to_vec(),
         )
-        .send()
         .await
         .map_err(|e| format!("Failed to delete objects: {}", e))?;
-
-    // 返回删除失败的对象
-    let errors = result
-        .errors()
-        .iter()
-        .map(|e| e.key().unwrap_or("").to_string())
-        .collect();
+        errors.extend(failures.into_iter().map(|f| f.key));
+    }
 
     Ok(errors)
 }
 
+/// 递归删除前缀下的所有 Objects
+///
+/// 分页列出对象，每页最多 1000 个 key 作为一个 DeleteObjects 批次并发执行，
+/// 进行中的批次达到上限时先等待完成再继续列出。
+/// 每个批次完成后发送一次 `delete-prefix-progress` 事件；取消后不再发起新批次，
+/// 等待已发出的批次完成后返回部分结果。列出失败时停止并在失败列表中记录该错误。
+/// 前缀按目录处理（缺少结尾的 / 时自动补上），其目录标记在全部删除成功后最后删除。
+#[tauri::command]
+pub async fn delete_prefix(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    config: S3Config,
+    bucket: String,
+    prefix: String,
+    task_id: String,
+) -> Result<BatchResult, String> {
+    // 空前缀会删除整个 Bucket 的内容，应使用强制删除 Bucket
+    if prefix.is_empty() {
+        return Err("Prefix must not be empty".to_string());
+    }
+    let prefix = folder_prefix(&prefix);
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let task = tasks.register(&task_id);
+
+    let mut progress = BatchProgress {
+        task_id: task_id.clone(),
+        processed: 0,
+        failed: 0,
+        current_key: None,
+    };
+    let mut result = BatchResult {
+        task_id,
+        succeeded: 0,
+        failures: Vec::new(),
+        cancelled: false,
+    };
+
+    // 汇总批次结果并返回累计失败数
+    let mut record = |batch: (Option<String>, u64, u64, Vec<BatchFailure>)| {
+        let (last_key, total, succeeded, failures) = batch;
+        progress.processed += total;
+        progress.failed += failures.len() as u64;
+        progress.current_key = last_key;
+        result.succeeded += succeeded;
+        result.failures.extend(failures);
+        let _ = app.emit("delete-prefix-progress", &progress);
+        progress.failed
+    };
+
+    // 目录标记最后删除，中途取消或失败时目录仍然可见
+    let mut has_marker = false;
+    let mut failed = 0;
+
+    let mut workers = JoinSet::new();
+    let mut continuation_token: Option<String> = None;
+    let mut list_error = None;
+
+    loop {
+        if task.is_cancelled() {
+            break;
+        }
+
+        let page = match client
+            .client()
+            .list_objects_v2()
+            .bucket(&bucket)
+            .set_request_payer(client.config().request_payer(&bucket))
+            .prefix(&prefix)
+            .max_keys(DELETE_BATCH_SIZE as i32)
+            .set_continuation_token(continuation_token.take())
+            .send()
+            .await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                list_error = Some(BatchFailure::from_sdk_error(&prefix, &e));
+                break;
+            }
+        };
+
+        let objects = page
+            .contents()
+            .iter()
+            .filter_map(|o| o.key())
+            .filter(|key| {
+                let is_marker = *key == prefix;
+                has_marker |= is_marker;
+                !is_marker
+            })
+            .map(|key| {
+                ObjectIdentifier::builder()
+                    .key(key)
+                    .build()
+                    .map_err(|e| e.to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for batch in objects.chunks(DELETE_BATCH_SIZE) {
+            // 限制进行中的批次数，避免列出速度快于删除时任务无限堆积
+            while workers.len() >= DELETE_CONCURRENCY {
+                if let Some(joined) = workers.join_next().await {
+                    failed = record(joined.map_err(|e| format!("Delete task failed: {}", e))?);
+                }
+            }
+
+            let s3 = client.client().clone();
+            let bucket = bucket.clone();
+            let request_payer = client.config().request_payer(&bucket);
+            let batch = batch.to_vec();
+
+            workers.spawn(async move {
+                let last_key = batch.last().map(|o| o.key().to_string());
+                let total = batch.len() as u64;
+                let (succeeded, failures) =
+                    delete_object_batch(&s3, &bucket, request_payer, batch).await;
+                (last_key, total, succeeded, failures)
+            });
+        }
+
+        // 汇报已完成的批次，避免等到列表结束才有进度
+        while let Some(joined) = workers.try_join_next() {
+            failed = record(joined.map_err(|e| format!("Delete task failed: {}", e))?);
+        }
+
+        match page.next_continuation_token() {
+            Some(token) if page.is_truncated().unwrap_or(false) => {
+                continuation_token = Some(token.to_string());
+            }
+            _ => break,
+        }
+    }
+
+    while let Some(joined) = workers.join_next().await {
+        failed = record(joined.map_err(|e| format!("Delete task failed: {}", e))?);
+    }
+
+    if let Some(failure) = list_error {
+        failed = record((None, 0, 0, vec![failure]));
+    }
+
+    if has_marker && !task.is_cancelled() && failed == 0 {
+        let identifier = ObjectIdentifier::builder()
+            .key(&prefix)
+            .build()
+            .map_err(|e| e.to_string())?;
+        let (succeeded, failures) = delete_object_batch(
+            client.client(),
+            &bucket,
+            client.config().request_payer(&bucket),
+            vec![identifier],
+        )
+        .await;
+        record((Some(prefix.clone()), 1, succeeded, failures));
+    }
+    result.cancelled = task.is_cancelled();
+
+    Ok(result)
+}
+
 /// 复制 Object
+///
+/// 大于 1GB 的对象使用分片复制，`source_version_id` 用于复制历史版本。
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn copy_object(
     config: S3Config,
     bucket: String,
     source_key: String,
     dest_key: String,
+    sse: Option<SseOptions>,
+    sse_customer_key: Option<String>,
+    source_sse_customer_key: Option<String>,
+    source_version_id: Option<String>,
 ) -> Result<(), String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    // 源和目标可以使用不同的客户密钥，用于更换 SSE-C 密钥
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
+    let source_sse_c = SseCustomerKey::resolve(source_sse_customer_key.as_deref())?;
+    if sse.is_some() && sse_c.is_some() {
+        return Err("SSE options and SSE-C key cannot be used together".to_string());
+    }
+    if let Some(sse) = &sse {
+        sse.server_side_encryption()?;
+    }
 
-    client
-        .client()
-        .copy_object()
-        .bucket(&bucket)
-        .copy_source(format!("{}/{}", bucket, source_key))
-        .key(&dest_key)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to copy object: {}", e))?;
+    let options = super::copy::CopyOptions {
+        source_version_id,
+        sse,
+        sse_c,
+        source_sse_c,
+        request_payer: client.config().request_payer(&bucket),
+        ..Default::default()
+    };
 
-    Ok(())
+    super::copy::copy_object_with(
+        client.client(),
+        &bucket,
+        &source_key,
+        &bucket,
+        &dest_key,
+        &options,
+    )
+    .await
+    .map_err(|f| format!("Failed to copy object: {}", f.message))
 }
 
 /// 获取 Object 元数据
@@ -160,23 +518,43 @@ pub async fn head_object(
     config: S3Config,
     bucket: String,
     key: String,
+    sse_customer_key: Option<String>,
 ) -> Result<ObjectMetadata, String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
 
-    let result = client
+    let request_payer = client.config().request_payer(&bucket);
+    let mut head_builder = client
         .client()
         .head_object()
         .bucket(&bucket)
         .key(&key)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to head object: {}", e))?;
+        .set_request_payer(request_payer.clone());
+    if let Some(k) = sse_c {
+        head_builder = head_builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(k.key)
+            .sse_customer_key_md5(k.key_md5);
+    }
+
+    // 标签数量需要单独请求，与 HeadObject 并发执行
+    let (result, tagging) = tokio::join!(
+        head_builder.send(),
+        client
+            .client()
+            .get_object_tagging()
+            .bucket(&bucket)
+            .key(&key)
+            .set_request_payer(request_payer)
+            .send(),
+    );
+    let result = result.map_err(|e| format!("Failed to head object: {}", e))?;
 
     Ok(ObjectMetadata {
         key,
         last_modified: result
             .last_modified()
-            .map(|d| format!("{:?}", d))
+            .map(format_timestamp)
             .unwrap_or_default(),
         size: result.content_length().unwrap_or(0) as i64,
         etag: result.e_tag().unwrap_or("").to_string(),
@@ -186,9 +564,226 @@ pub async fn head_object(
             .unwrap_or_default(),
         content_type: result.content_type().map(|s| s.to_string()),
         is_folder: false,
+        // 没有读取标签权限时不影响元数据获取
+        tag_count: tagging.ok().map(|t| t.tag_set().len() as i32),
+        server_side_encryption: result
+            .server_side_encryption()
+            .map(|s| s.as_str().to_string()),
+        sse_kms_key_id: result.ssekms_key_id().map(|s| s.to_string()),
+        replication_status: result.replication_status().map(|s| s.as_str().to_string()),
     })
 }
 
+/// 从 x-amz-restore、x-amz-expiration 等头中取出 `name="value"` 参数
+///
+/// 引号内的值可能包含逗号（如 HTTP 日期），需要按引号解析而不能直接按逗号拆分。
+fn header_param(header: &str, name: &str) -> Option<String> {
+    let mut rest = header;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        let (key, after) = rest.split_once('=')?;
+        let key = key.trim();
+
+        let (value, remaining) = if let Some(quoted) = after.strip_prefix('"') {
+            let mut parsed = String::new();
+            let mut chars = quoted.char_indices();
+            let mut end = None;
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '\\' => parsed.extend(chars.next().map(|(_, c)| c)),
+                    '"' => {
+                        end = Some(i);
+                        break;
+                    }
+                    _ => parsed.push(c),
+                }
+            }
+            (parsed, &quoted[end? + 1..])
+        } else {
+            let end = after.find(',').unwrap_or(after.len());
+            (after[..end].trim().to_string(), &after[end..])
+        };
+        rest = remaining;
+
+        if key == name {
+            return Some(value);
+        }
+    }
+}
+
+/// 分片上传的 ETag 以 `-分片数` 结尾
+fn multipart_etag_parts(etag: &str) -> Option<i32> {
+    let (_, parts) = etag.trim_matches('"').rsplit_once('-')?;
+    parts.parse().ok()
+}
+
+/// 将头中的 HTTP 日期转换为 RFC 3339，无法解析时保留原值
+fn header_date(header: &str, name: &str) -> Option<String> {
+    header_param(header, name).map(|d| {
+        parse_expires(&d)
+            .map(|date| format_timestamp(&date))
+            .unwrap_or(d)
+    })
+}
+
+/// 获取 Object 的完整信息（用户元数据、版本、加密、恢复状态、Object Lock、校验和等）
+#[tauri::command]
+pub async fn get_object_details(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    sse_customer_key: Option<String>,
+) -> Result<ObjectDetails, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
+
+    let head = |part_number: Option<i32>| {
+        let mut builder = client
+            .client()
+            .head_object()
+            .bucket(&bucket)
+            .key(&key)
+            .set_version_id(version_id.clone())
+            .set_part_number(part_number)
+            .checksum_mode(ChecksumMode::Enabled)
+            .set_request_payer(client.config().request_payer(&bucket));
+        if let Some(k) = &sse_c {
+            builder = builder
+                .sse_customer_algorithm(k.algorithm)
+                .sse_customer_key(&k.key)
+                .sse_customer_key_md5(&k.key_md5);
+        }
+        builder.send()
+    };
+
+    let result = head(None)
+        .await
+        .map_err(|e| format!("Failed to head object: {}", e))?;
+    // 只有指定 PartNumber 时才会返回分片数，仅对分片上传的对象再请求一次
+    let etag_parts = result.e_tag().and_then(multipart_etag_parts);
+    let parts_count = match etag_parts {
+        Some(parts) => head(Some(1))
+            .await
+            .ok()
+            .and_then(|p| p.parts_count())
+            .or(Some(parts)),
+        None => None,
+    };
+    let restore = result.restore();
+    let expiration = result.expiration();
+
+    Ok(ObjectDetails {
+        key,
+        version_id: result.version_id().map(|s| s.to_string()),
+        size: result.content_length().unwrap_or(0),
+        etag: result.e_tag().map(|s| s.to_string()),
+        last_modified: result.last_modified().map(format_timestamp),
+        storage_class: result
+            .storage_class()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_else(|| "STANDARD".to_string()),
+        delete_marker: result.delete_marker().unwrap_or(false),
+        content_type: result.content_type().map(|s| s.to_string()),
+        cache_control: result.cache_control().map(|s| s.to_string()),
+        content_disposition: result.content_disposition().map(|s| s.to_string()),
+        content_encoding: result.content_encoding().map(|s| s.to_string()),
+        content_language: result.content_language().map(|s| s.to_string()),
+        expires: result.expires_string().map(|s| s.to_string()),
+        website_redirect_location: result.website_redirect_location().map(|s| s.to_string()),
+        metadata: result
+            .metadata()
+            .map(|m| {
+                m.iter()
+                    .map(|(k, v)| (k.clone(), ObjectMetadataUpdate::decode_value(v)))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        tag_count: result.tag_count(),
+        server_side_encryption: result
+            .server_side_encryption()
+            .map(|s| s.as_str().to_string()),
+        sse_kms_key_id: result.ssekms_key_id().map(|s| s.to_string()),
+        bucket_key_enabled: result.bucket_key_enabled(),
+        sse_customer_algorithm: result.sse_customer_algorithm().map(|s| s.to_string()),
+        restore_in_progress: restore
+            .and_then(|r| header_param(r, "ongoing-request"))
+            .map(|v| v == "true"),
+        restore_expiry_date: restore.and_then(|r| header_date(r, "expiry-date")),
+        archive_status: result.archive_status().map(|s| s.as_str().to_string()),
+        expiration_date: expiration.and_then(|e| header_date(e, "expiry-date")),
+        expiration_rule_id: expiration.and_then(|e| header_param(e, "rule-id")),
+        object_lock_mode: result.object_lock_mode().map(|s| s.as_str().to_string()),
+        object_lock_retain_until_date: result.object_lock_retain_until_date().map(format_timestamp),
+        object_lock_legal_hold: result
+            .object_lock_legal_hold_status()
+            .map(|s| *s == ObjectLockLegalHoldStatus::On),
+        replication_status: result.replication_status().map(|s| s.as_str().to_string()),
+        checksum_crc32: result.checksum_crc32().map(|s| s.to_string()),
+        checksum_crc32c: result.checksum_crc32_c().map(|s| s.to_string()),
+        checksum_crc64nvme: result.checksum_crc64_nvme().map(|s| s.to_string()),
+        checksum_sha1: result.checksum_sha1().map(|s| s.to_string()),
+        checksum_sha256: result.checksum_sha256().map(|s| s.to_string()),
+        checksum_type: result.checksum_type().map(|s| s.as_str().to_string()),
+        parts_count,
+    })
+}
+
+/// 修改 Object 的系统元数据和用户元数据
+///
+/// 通过 REPLACE 指令复制到自身实现，大对象自动使用分片复制；标签、存储类型和加密方式保持不变。
+#[tauri::command]
+pub async fn update_object_metadata(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    metadata: ObjectMetadataUpdate,
+    sse_customer_key: Option<String>,
+) -> Result<(), String> {
+    metadata.validate()?;
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
+    let request_payer = client.config().request_payer(&bucket);
+
+    // 不指定加密参数时复制会改用 Bucket 默认加密，因此沿用原对象的 SSE 设置
+    let source_head = if sse_c.is_some() {
+        None
+    } else {
+        let head = client
+            .client()
+            .head_object()
+            .bucket(&bucket)
+            .key(&key)
+            .set_request_payer(request_payer.clone())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to head object: {}", e))?;
+        Some(head)
+    };
+    let sse = source_head.as_ref().and_then(|head| {
+        head.server_side_encryption().map(|algorithm| SseOptions {
+            algorithm: algorithm.as_str().to_string(),
+            kms_key_id: head.ssekms_key_id().map(|s| s.to_string()),
+            bucket_key_enabled: head.bucket_key_enabled(),
+        })
+    });
+
+    let options = super::copy::CopyOptions {
+        sse,
+        sse_c: sse_c.clone(),
+        source_sse_c: sse_c,
+        request_payer,
+        metadata: Some(metadata),
+        source_head,
+        ..Default::default()
+    };
+
+    super::copy::copy_object_with(client.client(), &bucket, &key, &bucket, &key, &options)
+        .await
+        .map_err(|f| format!("Failed to update object metadata: {}", f.message))
+}
+
 /// 移动/重命名 Object（通过复制+删除实现）
 #[tauri::command]
 pub async fn move_object(
@@ -196,19 +791,28 @@ pub async fn move_object(
     bucket: String,
     source_key: String,
     dest_key: String,
+    sse_customer_key: Option<String>,
 ) -> Result<(), String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
 
-    // 先复制
-    client
-        .client()
-        .copy_object()
-        .bucket(&bucket)
-        .copy_source(format!("{}/{}", bucket, source_key))
-        .key(&dest_key)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to copy object during move: {}", e))?;
+    // 先复制（SSE-C 对象移动后保持原密钥）
+    let options = super::copy::CopyOptions {
+        sse_c: sse_c.clone(),
+        source_sse_c: sse_c,
+        request_payer: client.config().request_payer(&bucket),
+        ..Default::default()
+    };
+    super::copy::copy_object_with(
+        client.client(),
+        &bucket,
+        &source_key,
+        &bucket,
+        &dest_key,
+        &options,
+    )
+    .await
+    .map_err(|f| format!("Failed to copy object during move: {}", f.message))?;
 
     // 再删除源文件
     client
@@ -216,6 +820,7 @@ pub async fn move_object(
         .delete_object()
         .bucket(&bucket)
         .key(&source_key)
+        .set_request_payer(client.config().request_payer(&bucket))
         .send()
         .await
         .map_err(|e| format!("Failed to delete source object during move: {}", e))?;
@@ -230,6 +835,557 @@ pub async fn rename_object(
     bucket: String,
     old_key: String,
     new_key: String,
+    sse_customer_key: Option<String>,
 ) -> Result<(), String> {
-    move_object(config, bucket, old_key, new_key).await
+    move_object(config, bucket, old_key, new_key, sse_customer_key).await
+}
+
+/// 用于判断两个对象内容是否一致的信息
+struct ContentIdentity<'a> {
+    size: Option<i64>,
+    etag: Option<&'a str>,
+    /// 完整对象校验和（算法，值）
+    checksum: Option<(&'static str, &'a str)>,
+}
+
+impl<'a> ContentIdentity<'a> {
+    /// 列表结果中不包含校验和
+    fn from_object(object: &'a aws_sdk_s3::types::Object) -> Self {
+        Self {
+            size: object.size(),
+            etag: object.e_tag(),
+            checksum: None,
+        }
+    }
+
+    /// 分片上传的组合校验和与分片方式有关，不用于比较
+    fn from_head(head: &'a HeadObjectOutput) -> Self {
+        let checksum = if head.checksum_type() == Some(&ChecksumType::Composite) {
+            None
+        } else {
+            [
+                ("CRC64NVME", head.checksum_crc64_nvme()),
+                ("CRC32", head.checksum_crc32()),
+                ("CRC32C", head.checksum_crc32_c()),
+                ("SHA1", head.checksum_sha1()),
+                ("SHA256", head.checksum_sha256()),
+            ]
+            .into_iter()
+            .find_map(|(algorithm, value)| value.map(|v| (algorithm, v)))
+        };
+
+        Self {
+            size: head.content_length(),
+            etag: head.e_tag(),
+            checksum,
+        }
+    }
+}
+
+/// 比较两个对象的内容是否一致
+///
+/// 大小不同时为 Some(false)，ETag 相同时为 Some(true)，否则比较同一算法的完整对象校验和。
+/// ETag 不同不代表内容不同（SSE-KMS 加密或分片复制后 ETag 会变化），无法比较时为 None。
+fn same_content(a: &ContentIdentity, b: &ContentIdentity) -> Option<bool> {
+    if a.size != b.size {
+        return Some(false);
+    }
+    if let (Some(x), Some(y)) = (a.etag, b.etag) {
+        if x.trim_matches('"') == y.trim_matches('"') {
+            return Some(true);
+        }
+    }
+    match (a.checksum, b.checksum) {
+        (Some((alg_a, a)), Some((alg_b, b))) if alg_a == alg_b => Some(a == b),
+        _ => None,
+    }
+}
+
+/// 读取对象属性并返回校验和
+async fn head_with_checksum(
+    s3: &aws_sdk_s3::Client,
+    bucket: &str,
+    key: &str,
+    request_payer: Option<RequestPayer>,
+) -> Result<HeadObjectOutput, SdkError<HeadObjectError>> {
+    s3.head_object()
+        .bucket(bucket)
+        .key(key)
+        .checksum_mode(ChecksumMode::Enabled)
+        .set_request_payer(request_payer)
+        .send()
+        .await
+}
+
+/// 比较源对象与目标对象，仅凭列表信息无法确定时读取源对象的校验和
+async fn compare_with_source(
+    s3: &aws_sdk_s3::Client,
+    bucket: &str,
+    source: &aws_sdk_s3::types::Object,
+    source_head: &mut Option<HeadObjectOutput>,
+    dest: &HeadObjectOutput,
+    request_payer: Option<RequestPayer>,
+) -> Result<Option<bool>, BatchFailure> {
+    let source_key = source.key().unwrap_or_default();
+    let dest = ContentIdentity::from_head(dest);
+    if let Some(same) = same_content(&ContentIdentity::from_object(source), &dest) {
+        return Ok(Some(same));
+    }
+    if dest.checksum.is_none() {
+        return Ok(None);
+    }
+
+    if source_head.is_none() {
+        let head = head_with_checksum(s3, bucket, source_key, request_payer)
+            .await
+            .map_err(|e| BatchFailure::from_sdk_error(source_key, &e))?;
+        *source_head = Some(head);
+    }
+    Ok(source_head
+        .as_ref()
+        .and_then(|head| same_content(&ContentIdentity::from_head(head), &dest)))
+}
+
+/// 复制单个对象并确认目标内容与源一致
+///
+/// 目标已存在且 ETag 或校验和与源一致时跳过（用于中断后续传），无法确认时重新复制。
+/// 复制后大小不一致，或 ETag、校验和可以比较但不一致时返回失败。
+/// `require_verified` 为 true 时（移动后会删除源对象），无法确认一致也返回失败。
+async fn copy_and_verify(
+    s3: aws_sdk_s3::Client,
+    bucket: String,
+    source: aws_sdk_s3::types::Object,
+    dest_key: String,
+    request_payer: Option<RequestPayer>,
+    require_verified: bool,
+) -> Result<String, BatchFailure> {
+    let source_key = source.key().unwrap_or_default().to_string();
+    let mut source_head = None;
+
+    if let Ok(existing) = head_with_checksum(&s3, &bucket, &dest_key, request_payer.clone()).await {
+        let same = compare_with_source(
+            &s3,
+            &bucket,
+            &source,
+            &mut source_head,
+            &existing,
+            request_payer.clone(),
+        )
+        .await?;
+        if same == Some(true) {
+            return Ok(source_key);
+        }
+    }
+
+    let options = super::copy::CopyOptions {
+        request_payer: request_payer.clone(),
+        source_head: source_head.clone(),
+        source_size: source.size(),
+        ..Default::default()
+    };
+    super::copy::copy_object_with(&s3, &bucket, &source_key, &bucket, &dest_key, &options).await?;
+
+    let copied = head_with_checksum(&s3, &bucket, &dest_key, request_payer.clone())
+        .await
+        .map_err(|e| BatchFailure::from_sdk_error(&source_key, &e))?;
+    let same = compare_with_source(
+        &s3,
+        &bucket,
+        &source,
+        &mut source_head,
+        &copied,
+        request_payer,
+    )
+    .await?;
+    match same {
+        Some(false) => Err(BatchFailure {
+            key: source_key,
+            code: None,
+            message: format!(
+                "Copied object does not match the source: expected {} bytes (ETag {}), got {} bytes (ETag {})",
+                source.size().unwrap_or(0),
+                source.e_tag().unwrap_or("-"),
+                copied.content_length().unwrap_or(0),
+                copied.e_tag().unwrap_or("-")
+            ),
+        }),
+        // 分片复制后 ETag 必然变化，没有同一算法的校验和时只能确认大小一致
+        None if require_verified => Err(BatchFailure {
+            key: source_key,
+            code: None,
+            message: "Copied object could not be verified against the source (ETag changed and no matching checksum), source kept".to_string(),
+        }),
+        _ => Ok(source_key),
+    }
+}
+
+/// 将源前缀下的所有对象复制到目标前缀，`delete_source` 为 true 时在校验通过后删除源对象
+///
+/// 两个前缀都按目录处理，缺少结尾的 / 时自动补上。列出失败时停止并返回部分结果。
+#[allow(clippy::too_many_arguments)]
+async fn transfer_prefix(
+    app: tauri::AppHandle,
+    tasks: &TaskManager,
+    config: S3Config,
+    bucket: String,
+    source_prefix: String,
+    dest_prefix: String,
+    task_id: String,
+    delete_source: bool,
+) -> Result<BatchResult, String> {
+    if source_prefix.is_empty() {
+        return Err("Source prefix must not be empty".to_string());
+    }
+    let source_prefix = folder_prefix(&source_prefix);
+    let dest_prefix = folder_prefix(&dest_prefix);
+    // 目标在源前缀之内时，新复制的对象会被再次列出
+    if dest_prefix.starts_with(&source_prefix) {
+        return Err("Destination prefix must not be inside the source prefix".to_string());
+    }
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let request_payer = client.config().request_payer(&bucket);
+    let task = tasks.register(&task_id);
+
+    let semaphore = Arc::new(Semaphore::new(COPY_CONCURRENCY));
+    let mut progress = BatchProgress {
+        task_id: task_id.clone(),
+        processed: 0,
+        failed: 0,
+        current_key: None,
+    };
+    let mut result = BatchResult {
+        task_id,
+        succeeded: 0,
+        failures: Vec::new(),
+        cancelled: false,
+    };
+    let mut continuation_token: Option<String> = None;
+
+    loop {
+        if task.is_cancelled() {
+            result.cancelled = true;
+            break;
+        }
+
+        // 列出失败时保留已完成部分的结果，并在失败列表中记录该错误
+        let page = match client
+            .client()
+            .list_objects_v2()
+            .bucket(&bucket)
+            .prefix(&source_prefix)
+            .set_continuation_token(continuation_token.take())
+            .set_request_payer(request_payer.clone())
+            .send()
+            .await
+        {
+            Ok(page) => page,
+            Err(e) => {
+                progress.failed += 1;
+                result
+                    .failures
+                    .push(BatchFailure::from_sdk_error(&source_prefix, &e));
+                let _ = app.emit("prefix-transfer-progress", &progress);
+                break;
+            }
+        };
+
+        let mut workers = JoinSet::new();
+        for object in page.contents() {
+            let Some(key) = object.key() else { continue };
+            let dest_key = format!("{}{}", dest_prefix, &key[source_prefix.len()..]);
+            let s3 = client.client().clone();
+            let bucket = bucket.clone();
+            let object = object.clone();
+            let request_payer = request_payer.clone();
+            let semaphore = semaphore.clone();
+
+            workers.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                copy_and_verify(s3, bucket, object, dest_key, request_payer, delete_source).await
+            });
+        }
+
+        // 只有复制并校验通过的源对象才会被删除
+        let mut copied = Vec::new();
+        while let Some(joined) = workers.join_next().await {
+            match joined.map_err(|e| format!("Copy task failed: {}", e))? {
+                Ok(key) => {
+                    progress.current_key = Some(key.clone());
+                    copied.push(key);
+                }
+                Err(failure) => {
+                    progress.failed += 1;
+                    progress.current_key = Some(failure.key.clone());
+                    result.failures.push(failure);
+                }
+            }
+            progress.processed += 1;
+            let _ = app.emit("prefix-transfer-progress", &progress);
+        }
+
+        if delete_source {
+            let objects = copied
+                .iter()
+                .map(|key| {
+                    ObjectIdentifier::builder()
+                        .key(key)
+                        .build()
+                        .map_err(|e| e.to_string())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            for batch in objects.chunks(DELETE_BATCH_SIZE) {
+                let (succeeded, failures) = delete_object_batch(
+                    client.client(),
+                    &bucket,
+                    client.config().request_payer(&bucket),
+                    batch.to_vec(),
+                )
+                .await;
+                progress.failed += failures.len() as u64;
+                result.succeeded += succeeded;
+                result.failures.extend(failures);
+            }
+            let _ = app.emit("prefix-transfer-progress", &progress);
+        } else {
+            result.succeeded += copied.len() as u64;
+        }
+
+        match page.next_continuation_token() {
+            Some(token) if page.is_truncated().unwrap_or(false) => {
+                continuation_token = Some(token.to_string());
+            }
+            _ => break,
+        }
+    }
+
+    Ok(result)
+}
+
+/// 复制前缀下的所有对象到目标前缀
+///
+/// 目标中已存在且 ETag 或校验和与源一致的对象会被跳过，中断后使用相同参数重新执行即可续传。
+/// 进度通过 `prefix-transfer-progress` 事件推送。
+#[tauri::command]
+pub async fn copy_prefix(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    config: S3Config,
+    bucket: String,
+    source_prefix: String,
+    dest_prefix: String,
+    task_id: String,
+) -> Result<BatchResult, String> {
+    transfer_prefix(
+        app,
+        &tasks,
+        config,
+        bucket,
+        source_prefix,
+        dest_prefix,
+        task_id,
+        false,
+    )
+    .await
+}
+
+/// 移动前缀下的所有对象到目标前缀
+///
+/// 每个源对象在复制并校验内容后才会删除，复制失败或无法校验的对象保留在原位置并记为失败。
+/// 已删除的源对象不会再被列出，中断后重新执行即可继续移动剩余对象。
+#[tauri::command]
+pub async fn move_prefix(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    config: S3Config,
+    bucket: String,
+    source_prefix: String,
+    dest_prefix: String,
+    task_id: String,
+) -> Result<BatchResult, String> {
+    transfer_prefix(
+        app,
+        &tasks,
+        config,
+        bucket,
+        source_prefix,
+        dest_prefix,
+        task_id,
+        true,
+    )
+    .await
+}
+
+/// 重命名文件夹（移动前缀的别名）
+#[tauri::command]
+pub async fn rename_prefix(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    config: S3Config,
+    bucket: String,
+    old_prefix: String,
+    new_prefix: String,
+    task_id: String,
+) -> Result<BatchResult, String> {
+    move_prefix(app, tasks, config, bucket, old_prefix, new_prefix, task_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity<'a>(
+        size: i64,
+        etag: &'a str,
+        checksum: Option<(&'static str, &'a str)>,
+    ) -> ContentIdentity<'a> {
+        ContentIdentity {
+            size: Some(size),
+            etag: Some(etag),
+            checksum,
+        }
+    }
+
+    #[test]
+    fn same_content_requires_matching_size() {
+        assert_eq!(
+            same_content(&identity(10, "\"a\"", None), &identity(11, "\"a\"", None)),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn same_content_matches_etags() {
+        assert_eq!(
+            same_content(&identity(10, "\"abc\"", None), &identity(10, "abc", None)),
+            Some(true)
+        );
+        assert_eq!(
+            same_content(
+                &identity(10, "\"abc-2\"", None),
+                &identity(10, "\"abc-2\"", None)
+            ),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn differing_etags_fall_back_to_checksums() {
+        // 分片复制或 SSE-KMS 会改变 ETag，仅凭 ETag 无法判断
+        assert_eq!(
+            same_content(
+                &identity(10, "\"abc-2\"", None),
+                &identity(10, "\"def\"", None)
+            ),
+            None
+        );
+        assert_eq!(
+            same_content(
+                &identity(10, "\"abc-2\"", Some(("CRC64NVME", "x"))),
+                &identity(10, "\"def\"", Some(("CRC64NVME", "x")))
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            same_content(
+                &identity(10, "\"abc-2\"", Some(("CRC64NVME", "x"))),
+                &identity(10, "\"def\"", Some(("CRC64NVME", "y")))
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            same_content(
+                &identity(10, "\"abc-2\"", Some(("CRC32", "x"))),
+                &identity(10, "\"def\"", Some(("CRC64NVME", "x")))
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn composite_checksums_are_ignored() {
+        let head = HeadObjectOutput::builder()
+            .content_length(10)
+            .e_tag("\"abc-2\"")
+            .checksum_crc32("AAAAAA==-2")
+            .checksum_type(ChecksumType::Composite)
+            .build();
+        assert_eq!(ContentIdentity::from_head(&head).checksum, None);
+
+        let head = HeadObjectOutput::builder()
+            .content_length(10)
+            .checksum_crc64_nvme("AAAAAAAAAAA=")
+            .checksum_type(ChecksumType::FullObject)
+            .build();
+        assert_eq!(
+            ContentIdentity::from_head(&head).checksum,
+            Some(("CRC64NVME", "AAAAAAAAAAA="))
+        );
+    }
+
+    #[test]
+    fn header_param_parses_quoted_dates() {
+        let restore = r#"ongoing-request="false", expiry-date="Fri, 23 Dec 2012 00:00:00 GMT""#;
+        assert_eq!(
+            header_param(restore, "ongoing-request").as_deref(),
+            Some("false")
+        );
+        assert_eq!(
+            header_param(restore, "expiry-date").as_deref(),
+            Some("Fri, 23 Dec 2012 00:00:00 GMT")
+        );
+
+        let expiration =
+            r#"expiry-date="Fri, 23 Dec 2012 00:00:00 GMT", rule-id="picture-deletion-rule""#;
+        assert_eq!(
+            header_param(expiration, "expiry-date").as_deref(),
+            Some("Fri, 23 Dec 2012 00:00:00 GMT")
+        );
+        assert_eq!(
+            header_param(expiration, "rule-id").as_deref(),
+            Some("picture-deletion-rule")
+        );
+        assert_eq!(header_param(expiration, "missing"), None);
+        assert_eq!(
+            header_date(expiration, "expiry-date").as_deref(),
+            Some("2012-12-23T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn header_param_handles_unquoted_and_escaped_values() {
+        assert_eq!(header_param("a=1, b=2", "b").as_deref(), Some("2"));
+        assert_eq!(
+            header_param(r#"rule-id="say \"hi\", ok""#, "rule-id").as_deref(),
+            Some(r#"say "hi", ok"#)
+        );
+        assert_eq!(header_param(r#"rule-id="unterminated"#, "rule-id"), None);
+    }
+
+    #[test]
+    fn multipart_etag_part_count() {
+        assert_eq!(
+            multipart_etag_parts("\"d41d8cd98f00b204e9800998ecf8427e-12\""),
+            Some(12)
+        );
+        assert_eq!(
+            multipart_etag_parts("\"d41d8cd98f00b204e9800998ecf8427e\""),
+            None
+        );
+    }
+
+    #[test]
+    fn only_empty_slash_keys_are_folder_markers() {
+        assert!(is_folder_marker("dir/", 0));
+        assert!(!is_folder_marker("dir/", 12));
+        assert!(!is_folder_marker("dir/file", 0));
+    }
+
+    #[test]
+    fn folder_prefix_appends_slash() {
+        assert_eq!(folder_prefix("logs"), "logs/");
+        assert_eq!(folder_prefix("logs/"), "logs/");
+        assert_eq!(folder_prefix("a/b"), "a/b/");
+        assert_eq!(folder_prefix(""), "");
+    }
 }
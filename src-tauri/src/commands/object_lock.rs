@@ -0,0 +1,356 @@
+use crate::s3::types::{format_timestamp, ObjectLockConfig, ObjectRetentionInfo, S3Config};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::primitives::{DateTime, DateTimeFormat};
+use aws_sdk_s3::types::{
+    DefaultRetention, ObjectLockConfiguration, ObjectLockEnabled, ObjectLockLegalHold,
+    ObjectLockLegalHoldStatus, ObjectLockRetention, ObjectLockRetentionMode, ObjectLockRule,
+};
+
+/// 解析保留模式
+fn parse_retention_mode(mode: &str) -> Result<ObjectLockRetentionMode, String> {
+    match mode {
+        "GOVERNANCE" => Ok(ObjectLockRetentionMode::Governance),
+        "COMPLIANCE" => Ok(ObjectLockRetentionMode::Compliance),
+        other => Err(format!("Unsupported retention mode: {}", other)),
+    }
+}
+
+/// 获取 Bucket Object Lock 配置
+#[tauri::command]
+pub async fn get_object_lock_configuration(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<ObjectLockConfig, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = match client
+        .client()
+        .get_object_lock_configuration()
+        .bucket(&bucket_name)
+        .send()
+        .await
+    {
+        Ok(result) => result,
+        // 创建时未启用 Object Lock 的 Bucket
+        Err(e) if e.code() == Some("ObjectLockConfigurationNotFoundError") => {
+            return Ok(ObjectLockConfig {
+                enabled: false,
+                mode: None,
+                days: None,
+                years: None,
+            });
+        }
+        Err(e) => return Err(format!("Failed to get object lock configuration: {}", e)),
+    };
+
+    let configuration = result.object_lock_configuration();
+    let retention = configuration
+        .and_then(|c| c.rule())
+        .and_then(|r| r.default_retention());
+
+    Ok(ObjectLockConfig {
+        enabled: configuration
+            .and_then(|c| c.object_lock_enabled())
+            .is_some_and(|e| *e == ObjectLockEnabled::Enabled),
+        mode: retention
+            .and_then(|r| r.mode())
+            .map(|m| m.as_str().to_string()),
+        days: retention.and_then(|r| r.days()),
+        years: retention.and_then(|r| r.years()),
+    })
+}
+
+/// 校验 ObjectLockConfig 并转换为 SDK 类型
+///
+/// Object Lock 启用后无法关闭，`enabled` 为 false 时直接拒绝。
+fn build_object_lock_configuration(
+    lock: &ObjectLockConfig,
+) -> Result<ObjectLockConfiguration, String> {
+    if !lock.enabled {
+        return Err("Object Lock cannot be disabled once enabled".to_string());
+    }
+
+    let mut builder =
+        ObjectLockConfiguration::builder().object_lock_enabled(ObjectLockEnabled::Enabled);
+
+    if let Some(mode) = &lock.mode {
+        let retention = match (lock.days, lock.years) {
+            (Some(days), None) if days > 0 => DefaultRetention::builder().days(days),
+            (None, Some(years)) if years > 0 => DefaultRetention::builder().years(years),
+            _ => {
+                return Err(
+                    "Default retention requires a positive value for either days or years"
+                        .to_string(),
+                )
+            }
+        };
+        let retention = retention.mode(parse_retention_mode(mode)?).build();
+        builder = builder.rule(
+            ObjectLockRule::builder()
+                .default_retention(retention)
+                .build(),
+        );
+    }
+
+    Ok(builder.build())
+}
+
+/// 设置 Bucket 默认保留规则
+///
+/// `mode` 为 None 时清除默认保留规则；设置时 `days` 和 `years` 只能指定其一。
+#[tauri::command]
+pub async fn put_object_lock_configuration(
+    config: S3Config,
+    bucket_name: String,
+    lock: ObjectLockConfig,
+) -> Result<(), String> {
+    let configuration = build_object_lock_configuration(&lock)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_object_lock_configuration()
+        .bucket(&bucket_name)
+        .object_lock_configuration(configuration)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put object lock configuration: {}", e))?;
+
+    Ok(())
+}
+
+/// 获取 Object 保留设置，未设置时返回 None
+#[tauri::command]
+pub async fn get_object_retention(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+) -> Result<Option<ObjectRetentionInfo>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = match client
+        .client()
+        .get_object_retention()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .send()
+        .await
+    {
+        Ok(result) => result,
+        Err(e) if e.code() == Some("NoSuchObjectLockConfiguration") => return Ok(None),
+        Err(e) => return Err(format!("Failed to get object retention: {}", e)),
+    };
+
+    Ok(result.retention().and_then(|r| {
+        Some(ObjectRetentionInfo {
+            mode: r.mode()?.as_str().to_string(),
+            retain_until_date: format_timestamp(r.retain_until_date()?),
+        })
+    }))
+}
+
+/// 设置 Object 保留设置
+///
+/// 缩短或移除 GOVERNANCE 保留需要 `bypass_governance`，COMPLIANCE 保留无法缩短。
+#[tauri::command]
+pub async fn put_object_retention(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    retention: ObjectRetentionInfo,
+    bypass_governance: Option<bool>,
+) -> Result<(), String> {
+    let retain_until = DateTime::from_str(&retention.retain_until_date, DateTimeFormat::DateTime)
+        .map_err(|e| format!("Invalid retain until date: {}", e))?;
+
+    let retention = ObjectLockRetention::builder()
+        .mode(parse_retention_mode(&retention.mode)?)
+        .retain_until_date(retain_until)
+        .build();
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_object_retention()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .retention(retention)
+        .set_bypass_governance_retention(bypass_governance)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put object retention: {}", e))?;
+
+    Ok(())
+}
+
+/// 获取 Object 法律保留状态
+#[tauri::command]
+pub async fn get_object_legal_hold(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+) -> Result<bool, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = match client
+        .client()
+        .get_object_legal_hold()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .send()
+        .await
+    {
+        Ok(result) => result,
+        Err(e) if e.code() == Some("NoSuchObjectLockConfiguration") => return Ok(false),
+        Err(e) => return Err(format!("Failed to get object legal hold: {}", e)),
+    };
+
+    Ok(result
+        .legal_hold()
+        .and_then(|h| h.status())
+        .is_some_and(|s| *s == ObjectLockLegalHoldStatus::On))
+}
+
+/// 开启或关闭 Object 法律保留
+#[tauri::command]
+pub async fn put_object_legal_hold(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    enabled: bool,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let status = if enabled {
+        ObjectLockLegalHoldStatus::On
+    } else {
+        ObjectLockLegalHoldStatus::Off
+    };
+
+    client
+        .client()
+        .put_object_legal_hold()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .legal_hold(ObjectLockLegalHold::builder().status(status).build())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put object legal hold: {}", e))?;
+
+    Ok(())
+}
+
+/// 绕过 GOVERNANCE 保留删除 Object 版本
+///
+/// 需要用户再次输入完整的 key 作为确认。
+#[tauri::command]
+pub async fn delete_object_bypass_governance(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+    confirmation: String,
+) -> Result<(), String> {
+    if confirmation != key {
+        return Err("Confirmation does not match the object key".to_string());
+    }
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .delete_object()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .bypass_governance_retention(true)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete object: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock(
+        enabled: bool,
+        mode: Option<&str>,
+        days: Option<i32>,
+        years: Option<i32>,
+    ) -> ObjectLockConfig {
+        ObjectLockConfig {
+            enabled,
+            mode: mode.map(|m| m.to_string()),
+            days,
+            years,
+        }
+    }
+
+    #[test]
+    fn parse_retention_mode_accepts_known_modes() {
+        assert_eq!(
+            parse_retention_mode("GOVERNANCE").unwrap(),
+            ObjectLockRetentionMode::Governance
+        );
+        assert_eq!(
+            parse_retention_mode("COMPLIANCE").unwrap(),
+            ObjectLockRetentionMode::Compliance
+        );
+        assert!(parse_retention_mode("governance").is_err());
+    }
+
+    #[test]
+    fn build_object_lock_configuration_sets_default_retention() {
+        let configuration =
+            build_object_lock_configuration(&lock(true, Some("COMPLIANCE"), Some(30), None))
+                .unwrap();
+        let retention = configuration
+            .rule()
+            .and_then(|r| r.default_retention())
+            .unwrap();
+        assert_eq!(retention.mode(), Some(&ObjectLockRetentionMode::Compliance));
+        assert_eq!(retention.days(), Some(30));
+        assert_eq!(retention.years(), None);
+
+        let configuration = build_object_lock_configuration(&lock(true, None, None, None)).unwrap();
+        assert_eq!(
+            configuration.object_lock_enabled(),
+            Some(&ObjectLockEnabled::Enabled)
+        );
+        assert!(configuration.rule().is_none());
+    }
+
+    #[test]
+    fn build_object_lock_configuration_rejects_invalid_settings() {
+        assert!(build_object_lock_configuration(&lock(false, None, None, None)).is_err());
+        assert!(
+            build_object_lock_configuration(&lock(true, Some("GOVERNANCE"), Some(1), Some(1)))
+                .is_err()
+        );
+        assert!(
+            build_object_lock_configuration(&lock(true, Some("GOVERNANCE"), None, None)).is_err()
+        );
+        assert!(
+            build_object_lock_configuration(&lock(true, Some("GOVERNANCE"), Some(0), None))
+                .is_err()
+        );
+    }
+}
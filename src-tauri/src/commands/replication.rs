@@ -0,0 +1,313 @@
+use crate::s3::types::{arn_partition, ReplicationConfig, ReplicationRuleInfo, S3Config};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::types::{
+    DeleteMarkerReplication, DeleteMarkerReplicationStatus, Destination, ReplicationConfiguration,
+    ReplicationRule, ReplicationRuleAndOperator, ReplicationRuleFilter, ReplicationRuleStatus,
+    StorageClass, Tag,
+};
+
+/// 复制规则使用的 ARN 分区，优先取 IAM 角色 ARN 中的分区，否则按区域判断
+fn replication_partition<'a>(role: &'a str, region: Option<&str>) -> &'a str {
+    role.strip_prefix("arn:")
+        .and_then(|rest| rest.split(':').next())
+        .filter(|p| !p.is_empty())
+        .unwrap_or_else(|| arn_partition(region))
+}
+
+/// 目标为 Bucket 名称时转换为所在分区的 ARN
+fn destination_arn(destination: &str, partition: &str) -> String {
+    if destination.starts_with("arn:") {
+        destination.to_string()
+    } else {
+        format!("arn:{}:s3:::{}", partition, destination)
+    }
+}
+
+fn replication_rule_from_sdk(rule: &ReplicationRule) -> ReplicationRuleInfo {
+    let filter = rule.filter();
+    let and = filter.and_then(|f| f.and());
+    let destination = rule.destination();
+
+    // 兼容旧版规则中直接设置的 Prefix
+    #[allow(deprecated)]
+    let legacy_prefix = rule.prefix();
+    let prefix = and
+        .and_then(|a| a.prefix())
+        .or_else(|| filter.and_then(|f| f.prefix()))
+        .or(legacy_prefix)
+        .filter(|p| !p.is_empty())
+        .map(|s| s.to_string());
+    let tags = match and {
+        Some(and) => super::tagging::to_tag_infos(and.tags()),
+        None => filter
+            .and_then(|f| f.tag())
+            .map(|tag| super::tagging::to_tag_infos(std::slice::from_ref(tag)))
+            .unwrap_or_default(),
+    };
+
+    ReplicationRuleInfo {
+        id: rule.id().map(|s| s.to_string()),
+        priority: rule.priority(),
+        enabled: *rule.status() == ReplicationRuleStatus::Enabled,
+        prefix,
+        tags,
+        destination_bucket: destination
+            .map(|d| d.bucket().to_string())
+            .unwrap_or_default(),
+        destination_account: destination.and_then(|d| d.account()).map(|s| s.to_string()),
+        storage_class: destination
+            .and_then(|d| d.storage_class())
+            .map(|c| c.as_str().to_string()),
+        delete_marker_replication: rule
+            .delete_marker_replication()
+            .and_then(|d| d.status())
+            .is_some_and(|s| *s == DeleteMarkerReplicationStatus::Enabled),
+    }
+}
+
+/// 校验 ReplicationRuleInfo 并转换为 SDK 类型，未指定优先级时按顺序编号
+fn replication_rule_to_sdk(
+    rule: ReplicationRuleInfo,
+    index: usize,
+    partition: &str,
+) -> Result<ReplicationRule, String> {
+    if rule.destination_bucket.trim().is_empty() {
+        return Err("Replication destination bucket is required".to_string());
+    }
+    // S3 不支持对带标签过滤的规则复制删除标记
+    if rule.delete_marker_replication && !rule.tags.is_empty() {
+        return Err("Delete marker replication is not supported for tag-based rules".to_string());
+    }
+
+    let storage_class = rule
+        .storage_class
+        .as_deref()
+        .map(|c| {
+            if StorageClass::values().contains(&c) {
+                Ok(StorageClass::from(c))
+            } else {
+                Err(format!("Unsupported storage class: {}", c))
+            }
+        })
+        .transpose()?;
+
+    let tags = rule
+        .tags
+        .iter()
+        .map(|t| {
+            Tag::builder()
+                .key(&t.key)
+                .value(&t.value)
+                .build()
+                .map_err(|e| e.to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // 多个过滤条件时必须使用 And 组合
+    let filter = if tags.len() + usize::from(rule.prefix.is_some()) > 1 {
+        ReplicationRuleFilter::builder()
+            .and(
+                ReplicationRuleAndOperator::builder()
+                    .set_prefix(rule.prefix)
+                    .set_tags(Some(tags))
+                    .build(),
+            )
+            .build()
+    } else if let Some(tag) = tags.into_iter().next() {
+        ReplicationRuleFilter::builder().tag(tag).build()
+    } else {
+        ReplicationRuleFilter::builder()
+            .prefix(rule.prefix.unwrap_or_default())
+            .build()
+    };
+
+    let destination = Destination::builder()
+        .bucket(destination_arn(&rule.destination_bucket, partition))
+        .set_account(rule.destination_account)
+        .set_storage_class(storage_class)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    ReplicationRule::builder()
+        .set_id(rule.id)
+        .priority(rule.priority.unwrap_or(index as i32 + 1))
+        .filter(filter)
+        .status(if rule.enabled {
+            ReplicationRuleStatus::Enabled
+        } else {
+            ReplicationRuleStatus::Disabled
+        })
+        .destination(destination)
+        .delete_marker_replication(
+            DeleteMarkerReplication::builder()
+                .status(if rule.delete_marker_replication {
+                    DeleteMarkerReplicationStatus::Enabled
+                } else {
+                    DeleteMarkerReplicationStatus::Disabled
+                })
+                .build(),
+        )
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// 获取 Bucket 复制配置，未配置时返回 None
+#[tauri::command]
+pub async fn get_bucket_replication(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<Option<ReplicationConfig>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    match client
+        .client()
+        .get_bucket_replication()
+        .bucket(&bucket_name)
+        .send()
+        .await
+    {
+        Ok(result) => Ok(result
+            .replication_configuration()
+            .map(|c| ReplicationConfig {
+                role: c.role().to_string(),
+                rules: c.rules().iter().map(replication_rule_from_sdk).collect(),
+            })),
+        Err(e) if e.code() == Some("ReplicationConfigurationNotFoundError") => Ok(None),
+        Err(e) => Err(format!("Failed to get bucket replication: {}", e)),
+    }
+}
+
+/// 设置 Bucket 复制配置（覆盖原有规则）
+///
+/// 源 Bucket 和目标 Bucket 都需要启用版本控制。
+#[tauri::command]
+pub async fn put_bucket_replication(
+    config: S3Config,
+    bucket_name: String,
+    replication: ReplicationConfig,
+) -> Result<(), String> {
+    if replication.rules.is_empty() {
+        return Err("At least one replication rule is required".to_string());
+    }
+
+    let partition = replication_partition(&replication.role, config.region.as_deref());
+    let rules = replication
+        .rules
+        .into_iter()
+        .enumerate()
+        .map(|(i, rule)| replication_rule_to_sdk(rule, i, partition))
+        .collect::<Result<Vec<_>, _>>()?;
+    let configuration = ReplicationConfiguration::builder()
+        .role(replication.role)
+        .set_rules(Some(rules))
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_replication()
+        .bucket(&bucket_name)
+        .replication_configuration(configuration)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket replication: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除 Bucket 复制配置
+#[tauri::command]
+pub async fn delete_bucket_replication(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .delete_bucket_replication()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete bucket replication: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(destination: &str) -> ReplicationRuleInfo {
+        ReplicationRuleInfo {
+            id: Some("rule".to_string()),
+            priority: None,
+            enabled: true,
+            prefix: None,
+            tags: Vec::new(),
+            destination_bucket: destination.to_string(),
+            destination_account: None,
+            storage_class: None,
+            delete_marker_replication: false,
+        }
+    }
+
+    #[test]
+    fn partition_prefers_role_arn() {
+        assert_eq!(
+            replication_partition(
+                "arn:aws-cn:iam::123456789012:role/replication",
+                Some("us-east-1")
+            ),
+            "aws-cn"
+        );
+        assert_eq!(replication_partition("", Some("cn-north-1")), "aws-cn");
+        assert_eq!(
+            replication_partition("", Some("us-gov-west-1")),
+            "aws-us-gov"
+        );
+        assert_eq!(replication_partition("", None), "aws");
+    }
+
+    #[test]
+    fn destination_arn_keeps_existing_arns() {
+        assert_eq!(
+            destination_arn("backup", "aws-cn"),
+            "arn:aws-cn:s3:::backup"
+        );
+        assert_eq!(
+            destination_arn("arn:minio:replication::id:backup", "aws"),
+            "arn:minio:replication::id:backup"
+        );
+    }
+
+    #[test]
+    fn rule_uses_partition_for_destination() {
+        let rule = replication_rule_to_sdk(rule("backup"), 0, "aws-us-gov").unwrap();
+        assert_eq!(rule.priority(), Some(1));
+        assert_eq!(
+            rule.destination().map(|d| d.bucket()),
+            Some("arn:aws-us-gov:s3:::backup")
+        );
+    }
+
+    #[test]
+    fn rule_validation() {
+        assert!(replication_rule_to_sdk(rule(" "), 0, "aws").is_err());
+
+        let mut tagged = rule("backup");
+        tagged.tags = vec![crate::s3::types::TagInfo {
+            key: "env".to_string(),
+            value: "prod".to_string(),
+        }];
+        tagged.delete_marker_replication = true;
+        assert!(replication_rule_to_sdk(tagged, 0, "aws").is_err());
+
+        let mut storage = rule("backup");
+        storage.storage_class = Some("FAST".to_string());
+        assert!(replication_rule_to_sdk(storage, 0, "aws").is_err());
+    }
+}
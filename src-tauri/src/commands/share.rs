@@ -0,0 +1,438 @@
+use crate::s3::types::{S3Config, ShareRecord};
+use crate::s3::client::S3Client;
+use crate::s3::ShareRegistry;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tauri::Manager;
+
+const REVOKE_ROTATE_KEY: &str = "rotate_key";
+const REVOKE_POLICY: &str = "policy";
+
+/// 分享记录文件路径
+fn registry_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("shares.json"))
+        .map_err(|e| format!("Failed to resolve app data dir: {}", e))
+}
+
+fn now_rfc3339() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// 同一连接下的同一对象（版本 ID 不同也视为同一对象）
+fn same_object(record: &ShareRecord, config_id: &str, bucket: &str, key: &str) -> bool {
+    record.config_id == config_id && record.bucket == bucket && record.key == key
+}
+
+/// 撤销 `revoked` 时 `other` 是否也随之失效
+///
+/// `policy` 拒绝该对象所有版本的预签名访问；`rotate_key` 只删除了分享的那个版本，
+/// 未指定版本时只产生删除标记，带版本 ID 的链接仍然可用。
+fn invalidated_by(method: &str, revoked: &ShareRecord, other: &ShareRecord) -> bool {
+    same_object(other, &revoked.config_id, &revoked.bucket, &revoked.key)
+        && (method == REVOKE_POLICY || other.version_id == revoked.version_id)
+}
+
+/// 用主密码加密旧版本记录中的明文 URL
+fn encrypt_legacy_urls(records: &mut [ShareRecord], password: &str) -> Result<(), String> {
+    for record in records.iter_mut().filter(|r| r.encrypted_url.is_none()) {
+        if let Some(url) = record.url.take() {
+            record.encrypted_url = Some(super::security::encrypt_text(url, password.to_string())?);
+        }
+    }
+    Ok(())
+}
+
+/// 策略 Resource 中的对象 ARN
+///
+/// key 中的 `*`、`?` 和 `$` 用策略变量转义，避免被当作通配符匹配到其他对象。
+fn object_arn(partition: &str, bucket: &str, key: &str) -> String {
+    let mut escaped = String::with_capacity(key.len());
+    for c in key.chars() {
+        match c {
+            '*' | '?' | '$' => {
+                escaped.push_str("${");
+                escaped.push(c);
+                escaped.push('}');
+            }
+            c => escaped.push(c),
+        }
+    }
+    format!("arn:{}:s3:::{}/{}", partition, bucket, escaped)
+}
+
+/// 生成预签名下载链接并记录到本地分享记录
+///
+/// `created_by` 为空时使用连接的 Access Key ID。URL 用 `password`（主密码）加密后保存，
+/// 对象已通过 Bucket 策略撤销过分享时，新链接同样记录为已撤销。
+#[allow(clippy::too_many_arguments)]
+#[tauri::command]
+pub async fn create_share_link(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, Arc<ShareRegistry>>,
+    config: S3Config,
+    password: String,
+    bucket: String,
+    key: String,
+    expires_in_secs: u64,
+    version_id: Option<String>,
+    response_content_disposition: Option<String>,
+    note: Option<String>,
+    created_by: Option<String>,
+) -> Result<ShareRecord, String> {
+    let path = registry_path(&app)?;
+    let config_id = config.id.clone();
+    let connection_name = config.name.clone();
+    let access_key_id = config.access_key_id.clone();
+    let credentials_expire_at = config
+        .session_token
+        .as_ref()
+        .and(config.session_expires_at.clone());
+
+    let presigned = super::presign::presign_get_object(
+        config,
+        bucket.clone(),
+        key.clone(),
+        expires_in_secs,
+        response_content_disposition,
+        None,
+        version_id.clone(),
+    )
+    .await?;
+
+    let mut record = ShareRecord {
+        id: uuid::Uuid::new_v4().to_string(),
+        config_id,
+        connection_name,
+        bucket,
+        key,
+        version_id,
+        encrypted_url: Some(super::security::encrypt_text(
+            presigned.url.clone(),
+            password.clone(),
+        )?),
+        url: Some(presigned.url),
+        created_by: created_by
+            .filter(|c| !c.trim().is_empty())
+            .unwrap_or(access_key_id),
+        created_at: now_rfc3339(),
+        expires_at: presigned.expires_at,
+        credentials_expire_at,
+        note: note.filter(|n| !n.trim().is_empty()),
+        revoked_at: None,
+        revocation: None,
+        rotated_to: None,
+        status: String::new(),
+    };
+
+    registry
+        .update(&path, move |records| {
+            encrypt_legacy_urls(records, &password)?;
+
+            // Bucket 策略中的拒绝语句对之后生成的链接同样生效
+            if let Some(revoked) = records.iter().find(|r| {
+                r.revocation.as_deref() == Some(REVOKE_POLICY)
+                    && same_object(r, &record.config_id, &record.bucket, &record.key)
+            }) {
+                record.revoked_at = revoked.revoked_at.clone();
+                record.revocation = Some(REVOKE_POLICY.to_string());
+            }
+            record.status = crate::s3::share::share_status(&record, chrono::Utc::now()).to_string();
+
+            records.push(record.clone());
+            Ok(record)
+        })
+        .await
+}
+
+/// 列出分享记录，按创建时间倒序
+///
+/// `config_id` 为空时列出所有连接的记录，`include_inactive` 为 false 时只返回有效链接。
+#[tauri::command]
+pub async fn list_share_links(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, Arc<ShareRegistry>>,
+    config_id: Option<String>,
+    include_inactive: bool,
+) -> Result<Vec<ShareRecord>, String> {
+    let path = registry_path(&app)?;
+    let mut records: Vec<ShareRecord> = registry
+        .list(&path)
+        .await?
+        .into_iter()
+        .filter(|r| config_id.as_ref().is_none_or(|id| &r.config_id == id))
+        .filter(|r| include_inactive || r.status == crate::s3::share::SHARE_ACTIVE)
+        .map(|r| ShareRecord { url: None, ..r })
+        .collect();
+    records.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(records)
+}
+
+/// 获取仍然有效的分享链接，用于再次复制
+///
+/// `password` 为加密 URL 时使用的主密码。
+#[tauri::command]
+pub async fn get_share_link_url(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, Arc<ShareRegistry>>,
+    id: String,
+    password: String,
+) -> Result<String, String> {
+    let path = registry_path(&app)?;
+    let record = registry
+        .update(&path, |records| {
+            encrypt_legacy_urls(records, &password)?;
+            records
+                .iter()
+                .find(|r| r.id == id)
+                .cloned()
+                .ok_or_else(|| format!("Share link {} not found", id))
+        })
+        .await?;
+
+    match crate::s3::share::share_status(&record, chrono::Utc::now()) {
+        crate::s3::share::SHARE_ACTIVE => {
+            let encrypted = record.encrypted_url.ok_or("Share link URL was not saved")?;
+            super::security::decrypt_text(encrypted, password)
+        }
+        status => Err(format!("Share link is {}", status)),
+    }
+}
+
+/// 从本地记录中删除分享，不影响已发出的链接
+#[tauri::command]
+pub async fn delete_share_link(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, Arc<ShareRegistry>>,
+    id: String,
+) -> Result<(), String> {
+    let path = registry_path(&app)?;
+    registry
+        .update(&path, |records| {
+            records.retain(|r| r.id != id);
+            Ok(())
+        })
+        .await
+}
+
+/// 在 Bucket 策略中追加拒绝该对象（包括所有版本）预签名访问的语句
+async fn deny_presigned_access(
+    client: &aws_sdk_s3::Client,
+    partition: &str,
+    bucket: &str,
+    key: &str,
+    share_id: &str,
+) -> Result<(), String> {
+    let mut policy = match super::access::load_bucket_policy(client, bucket).await? {
+        Some(policy) => serde_json::from_str::<serde_json::Value>(&policy)
+            .map_err(|e| format!("Failed to parse bucket policy: {}", e))?,
+        None => serde_json::json!({ "Version": "2012-10-17", "Statement": [] }),
+    };
+
+    // 策略变量（转义通配符所需）只在 2012-10-17 版本中可用
+    let resource = object_arn(partition, bucket, key);
+    if key.contains(['*', '?', '$'])
+        && policy.get("Version").and_then(|v| v.as_str()) != Some("2012-10-17")
+    {
+        return Err(
+            "Bucket policy version does not support escaping wildcard characters in the key"
+                .to_string(),
+        );
+    }
+
+    // Sid 只允许字母和数字
+    let statement = serde_json::json!({
+        "Sid": format!("RevokeShare{}", share_id.replace('-', "")),
+        "Effect": "Deny",
+        "Principal": "*",
+        "Action": ["s3:GetObject", "s3:GetObjectVersion"],
+        "Resource": resource,
+        "Condition": { "StringEquals": { "s3:authType": "REST-QUERY-STRING" } },
+    });
+
+    // 单条语句的策略可能不是数组形式
+    let statements = match policy.get_mut("Statement") {
+        Some(serde_json::Value::Array(statements)) => statements,
+        Some(statement) => {
+            *statement = serde_json::Value::Array(vec![statement.take()]);
+            statement.as_array_mut().unwrap()
+        }
+        None => return Err("Bucket policy has no Statement".to_string()),
+    };
+    statements.push(statement);
+
+    client
+        .put_bucket_policy()
+        .bucket(bucket)
+        .policy(policy.to_string())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket policy: {}", e))?;
+
+    Ok(())
+}
+
+/// 撤销分享链接
+///
+/// `rotate_key` 将分享的版本移动到 `new_key`，同一版本（或同样未指定版本）的链接随之失效；
+/// `policy` 在 Bucket 策略中拒绝该对象所有版本的预签名访问，同一对象的所有链接都会失效。
+/// 失效的记录一并标记为已撤销。
+#[tauri::command]
+pub async fn revoke_share_link(
+    app: tauri::AppHandle,
+    registry: tauri::State<'_, Arc<ShareRegistry>>,
+    config: S3Config,
+    id: String,
+    method: String,
+    new_key: Option<String>,
+) -> Result<(), String> {
+    let path = registry_path(&app)?;
+    let record = registry
+        .list(&path)
+        .await?
+        .into_iter()
+        .find(|r| r.id == id)
+        .ok_or_else(|| format!("Share link {} not found", id))?;
+    if record.config_id != config.id {
+        return Err("Share link belongs to another connection".to_string());
+    }
+    if record.revoked_at.is_some() {
+        return Err("Share link is already revoked".to_string());
+    }
+
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let rotated_to = match method.as_str() {
+        REVOKE_ROTATE_KEY => {
+            let new_key = new_key
+                .filter(|k| !k.is_empty() && *k != record.key)
+                .ok_or("A new key different from the shared key is required")?;
+            let request_payer = client.config().request_payer(&record.bucket);
+            let options = super::copy::CopyOptions {
+                source_version_id: record.version_id.clone(),
+                request_payer: request_payer.clone(),
+                ..Default::default()
+            };
+
+            super::copy::copy_object_with(
+                client.client(),
+                &record.bucket,
+                &record.key,
+                &record.bucket,
+                &new_key,
+                &options,
+            )
+            .await
+            .map_err(|f| format!("Failed to copy object: {}", f.message))?;
+
+            // 链接带版本 ID 时需要永久删除该版本，否则删除标记不会使链接失效
+            client
+                .client()
+                .delete_object()
+                .bucket(&record.bucket)
+                .key(&record.key)
+                .set_version_id(record.version_id.clone())
+                .set_request_payer(request_payer)
+                .send()
+                .await
+                .map_err(|e| format!("Failed to delete shared object: {}", e))?;
+
+            Some(new_key)
+        }
+        REVOKE_POLICY => {
+            let partition = crate::s3::types::arn_partition(client.config().region.as_deref());
+            deny_presigned_access(
+                client.client(),
+                partition,
+                &record.bucket,
+                &record.key,
+                &record.id,
+            )
+            .await?;
+            None
+        }
+        _ => return Err(format!("Unsupported revocation method: {}", method)),
+    };
+
+    let revoked_at = now_rfc3339();
+    registry
+        .update(&path, |records| {
+            for r in records
+                .iter_mut()
+                .filter(|r| r.revoked_at.is_none() && invalidated_by(&method, &record, r))
+            {
+                r.revoked_at = Some(revoked_at.clone());
+                r.revocation = Some(method.clone());
+                r.rotated_to = rotated_to.clone();
+            }
+            Ok(())
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(key: &str, version_id: Option<&str>) -> ShareRecord {
+        serde_json::from_value(serde_json::json!({
+            "id": "1", "config_id": "c", "connection_name": "n", "bucket": "b", "key": key,
+            "version_id": version_id, "created_by": "me",
+            "created_at": "2026-01-01T00:00:00Z", "expires_at": "2026-01-02T00:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn rotate_key_only_invalidates_same_version() {
+        let latest = record("a.txt", None);
+        let v1 = record("a.txt", Some("v1"));
+        let v2 = record("a.txt", Some("v2"));
+
+        assert!(invalidated_by(REVOKE_ROTATE_KEY, &v1, &v1));
+        assert!(!invalidated_by(REVOKE_ROTATE_KEY, &v1, &v2));
+        assert!(!invalidated_by(REVOKE_ROTATE_KEY, &v1, &latest));
+        assert!(invalidated_by(REVOKE_ROTATE_KEY, &latest, &latest));
+        assert!(!invalidated_by(REVOKE_ROTATE_KEY, &latest, &v1));
+    }
+
+    #[test]
+    fn policy_invalidates_all_versions_of_the_object() {
+        let latest = record("a.txt", None);
+        let v1 = record("a.txt", Some("v1"));
+
+        assert!(invalidated_by(REVOKE_POLICY, &v1, &latest));
+        assert!(invalidated_by(REVOKE_POLICY, &latest, &v1));
+        assert!(!invalidated_by(
+            REVOKE_POLICY,
+            &latest,
+            &record("b.txt", None)
+        ));
+    }
+
+    #[test]
+    fn object_arn_escapes_wildcards() {
+        assert_eq!(
+            object_arn("aws", "b", "dir/a.txt"),
+            "arn:aws:s3:::b/dir/a.txt"
+        );
+        assert_eq!(
+            object_arn("aws-cn", "b", "a*b?$c"),
+            "arn:aws-cn:s3:::b/a${*}b${?}${$}c"
+        );
+    }
+
+    #[test]
+    fn legacy_urls_are_encrypted() {
+        let mut records = vec![record("a.txt", None)];
+        records[0].url = Some("https://example.com/a.txt".to_string());
+
+        encrypt_legacy_urls(&mut records, "pw").unwrap();
+        assert!(records[0].url.is_none());
+        let encrypted = records[0].encrypted_url.clone().unwrap();
+        assert_eq!(
+            super::super::security::decrypt_text(encrypted, "pw".to_string()).unwrap(),
+            "https://example.com/a.txt"
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::s3::types::{
+    is_unsupported_error, BatchFailure, BatchProgress, BatchResult, S3Config, TagInfo,
+};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::types::{Tag, Tagging};
+use std::sync::Arc;
+use tauri::Emitter;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+// S3 标签限制
+pub(crate) const MAX_BUCKET_TAGS: usize = 50;
+const MAX_OBJECT_TAGS: usize = 10;
+const MAX_TAG_KEY_LEN: usize = 128;
+const MAX_TAG_VALUE_LEN: usize = 256;
+
+// 批量打标签时的并发数
+const TAGGING_CONCURRENCY: usize = 8;
+
+/// 校验标签并构造 Tagging
+pub(crate) fn build_tagging(tags: &[TagInfo], max_tags: usize) -> Result<Tagging, String> {
+    if tags.len() > max_tags {
+        return Err(format!("Too many tags: {} (max {})", tags.len(), max_tags));
+    }
+
+    let mut tag_set = Vec::with_capacity(tags.len());
+    for tag in tags {
+        if tag.key.is_empty() || tag.key.chars().count() > MAX_TAG_KEY_LEN {
+            return Err(format!("Invalid tag key: '{}'", tag.key));
+        }
+        if tag.value.chars().count() > MAX_TAG_VALUE_LEN {
+            return Err(format!("Tag value too long for key '{}'", tag.key));
+        }
+        if tag_set.iter().any(|t: &Tag| t.key() == tag.key) {
+            return Err(format!("Duplicate tag key: '{}'", tag.key));
+        }
+        tag_set.push(
+            Tag::builder()
+                .key(&tag.key)
+                .value(&tag.value)
+                .build()
+                .map_err(|e| e.to_string())?,
+        );
+    }
+
+    Tagging::builder()
+        .set_tag_set(Some(tag_set))
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+pub(crate) fn to_tag_infos(tags: &[Tag]) -> Vec<TagInfo> {
+    tags.iter()
+        .map(|t| TagInfo {
+            key: t.key().to_string(),
+            value: t.value().to_string(),
+        })
+        .collect()
+}
+
+/// 读取 Bucket 标签，未设置时返回空列表
+pub(crate) async fn load_bucket_tagging(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Vec<TagInfo>, String> {
+    match client.get_bucket_tagging().bucket(bucket).send().await {
+        Ok(result) => Ok(to_tag_infos(result.tag_set())),
+        // 未设置标签时 S3 返回 NoSuchTagSet
+        Err(e) if e.code() == Some("NoSuchTagSet") || is_unsupported_error(e.code()) => {
+            Ok(Vec::new())
+        }
+        Err(e) => Err(format!("Failed to get bucket tagging: {}", e)),
+    }
+}
+
+/// 获取 Bucket 标签
+#[tauri::command]
+pub async fn get_bucket_tagging(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<Vec<TagInfo>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    load_bucket_tagging(client.client(), &bucket_name).await
+}
+
+/// 设置 Bucket 标签（覆盖原有标签）
+#[tauri::command]
+pub async fn put_bucket_tagging(
+    config: S3Config,
+    bucket_name: String,
+    tags: Vec<TagInfo>,
+) -> Result<(), String> {
+    let tagging = build_tagging(&tags, MAX_BUCKET_TAGS)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_tagging()
+        .bucket(&bucket_name)
+        .tagging(tagging)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket tagging: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除 Bucket 标签
+#[tauri::command]
+pub async fn delete_bucket_tagging(config: S3Config, bucket_name: String) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .delete_bucket_tagging()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete bucket tagging: {}", e))?;
+
+    Ok(())
+}
+
+/// 获取 Object 标签
+#[tauri::command]
+pub async fn get_object_tagging(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+) -> Result<Vec<TagInfo>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let result = client
+        .client()
+        .get_object_tagging()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to get object tagging: {}", e))?;
+
+    Ok(to_tag_infos(result.tag_set()))
+}
+
+/// 设置 Object 标签（覆盖原有标签）
+#[tauri::command]
+pub async fn put_object_tagging(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    tags: Vec<TagInfo>,
+    version_id: Option<String>,
+) -> Result<(), String> {
+    let tagging = build_tagging(&tags, MAX_OBJECT_TAGS)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_object_tagging()
+        .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
+        .key(&key)
+        .set_version_id(version_id)
+        .tagging(tagging)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put object tagging: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除 Object 标签
+///
+/// SDK 的 DeleteObjectTagging 没有 request_payer 参数，请求者付费时在签名前直接添加请求头。
+#[tauri::command]
+pub async fn delete_object_tagging(
+    config: S3Config,
+    bucket: String,
+    key: String,
+    version_id: Option<String>,
+) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let request_payer = client.config().request_payer(&bucket);
+
+    client
+        .client()
+        .delete_object_tagging()
+        .bucket(&bucket)
+        .key(&key)
+        .set_version_id(version_id)
+        .customize()
+        .mutate_request(move |request| {
+            if let Some(payer) = &request_payer {
+                request
+                    .headers_mut()
+                    .insert("x-amz-request-payer", payer.as_str().to_string());
+            }
+        })
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete object tagging: {}", e))?;
+
+    Ok(())
+}
+
+/// 为前缀下的所有 Object 设置标签
+///
+/// 每处理完一个对象发送一次 `tagging-progress` 事件，单个对象失败不会中断整个任务。
+#[tauri::command]
+pub async fn apply_tags_to_prefix(
+    app: tauri::AppHandle,
+    config: S3Config,
+    bucket: String,
+    prefix: String,
+    tags: Vec<TagInfo>,
+    task_id: String,
+) -> Result<BatchResult, String> {
+    let tagging = build_tagging(&tags, MAX_OBJECT_TAGS)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    let semaphore = Arc::new(Semaphore::new(TAGGING_CONCURRENCY));
+    let mut progress = BatchProgress {
+        task_id: task_id.clone(),
+        processed: 0,
+        failed: 0,
+        current_key: None,
+    };
+    let mut succeeded = 0;
+    let mut failures = Vec::new();
+    let mut continuation_token: Option<String> = None;
+
+    loop {
+        let page = client
+            .client()
+            .list_objects_v2()
+            .bucket(&bucket)
+            .set_request_payer(client.config().request_payer(&bucket))
+            .prefix(&prefix)
+            .set_continuation_token(continuation_token.take())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list objects: {}", e))?;
+
+        let mut tasks = JoinSet::new();
+        for key in page.contents().iter().filter_map(|o| o.key()) {
+            let s3 = client.client().clone();
+            let bucket = bucket.clone();
+            let request_payer = client.config().request_payer(&bucket);
+            let key = key.to_string();
+            let tagging = tagging.clone();
+            let semaphore = semaphore.clone();
+
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await;
+                let result = s3
+                    .put_object_tagging()
+                    .bucket(&bucket)
+                    .set_request_payer(request_payer)
+                    .key(&key)
+                    .tagging(tagging)
+                    .send()
+                    .await;
+                match result {
+                    Ok(_) => Ok(key),
+                    Err(e) => Err(BatchFailure::from_sdk_error(key, &e)),
+                }
+            });
+        }
+
+        while let Some(joined) = tasks.join_next().await {
+            match joined.map_err(|e| format!("Tagging task failed: {}", e))? {
+                Ok(key) => {
+                    succeeded += 1;
+                    progress.current_key = Some(key);
+                }
+                Err(failure) => {
+                    progress.failed += 1;
+                    progress.current_key = Some(failure.key.clone());
+                    failures.push(failure);
+                }
+            }
+            progress.processed += 1;
+            let _ = app.emit("tagging-progress", &progress);
+        }
+
+        match page.next_continuation_token() {
+            Some(token) if page.is_truncated().unwrap_or(false) => {
+                continuation_token = Some(token.to_string());
+            }
+            _ => break,
+        }
+    }
+
+    Ok(BatchResult {
+        task_id,
+        succeeded,
+        failures,
+        cancelled: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(key: &str, value: &str) -> TagInfo {
+        TagInfo {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    #[test]
+    fn build_tagging_accepts_valid_tags() {
+        let tagging =
+            build_tagging(&[tag("env", "prod"), tag("team", "")], MAX_OBJECT_TAGS).unwrap();
+        assert_eq!(to_tag_infos(tagging.tag_set()).len(), 2);
+        assert_eq!(tagging.tag_set()[0].key(), "env");
+        assert_eq!(tagging.tag_set()[1].value(), "");
+    }
+
+    #[test]
+    fn build_tagging_rejects_invalid_tags() {
+        let too_many: Vec<_> = (0..=MAX_OBJECT_TAGS)
+            .map(|i| tag(&i.to_string(), "v"))
+            .collect();
+        assert!(build_tagging(&too_many, MAX_OBJECT_TAGS).is_err());
+        assert!(build_tagging(&[tag("", "v")], MAX_OBJECT_TAGS).is_err());
+        assert!(build_tagging(
+            &[tag(&"k".repeat(MAX_TAG_KEY_LEN + 1), "v")],
+            MAX_OBJECT_TAGS
+        )
+        .is_err());
+        assert!(build_tagging(
+            &[tag("k", &"v".repeat(MAX_TAG_VALUE_LEN + 1))],
+            MAX_OBJECT_TAGS
+        )
+        .is_err());
+        assert!(build_tagging(&[tag("k", "a"), tag("k", "b")], MAX_OBJECT_TAGS).is_err());
+    }
+
+    #[test]
+    fn build_tagging_counts_characters_not_bytes() {
+        let key = "标".repeat(MAX_TAG_KEY_LEN);
+        assert!(build_tagging(&[tag(&key, "值")], MAX_BUCKET_TAGS).is_ok());
+    }
+}
@@ -0,0 +1,8 @@
+use crate::s3::TaskManager;
+use std::sync::Arc;
+
+/// 取消正在运行的批量任务
+#[tauri::command]
+pub fn cancel_task(tasks: tauri::State<'_, Arc<TaskManager>>, task_id: String) -> bool {
+    tasks.cancel(&task_id)
+}
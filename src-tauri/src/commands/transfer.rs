@@ -0,0 +1,381 @@
+use crate::s3::types::S3Config;
+use crate::s3::client::S3Client;
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart, Tagging};
+
+// 跨连接流式复制时超过该大小使用分片上传
+const STREAM_MULTIPART_THRESHOLD: i64 = 64 * 1024 * 1024;
+const STREAM_PART_SIZE: i64 = 16 * 1024 * 1024;
+const MAX_PARTS: i64 = 10_000;
+
+/// 两个连接是否指向同一服务且使用相同凭证，此时可以直接使用服务端复制
+fn same_connection(source: &S3Config, dest: &S3Config) -> bool {
+    source.endpoint == dest.endpoint
+        && source.access_key_id == dest.access_key_id
+        && source.secret_access_key == dest.secret_access_key
+        && source.session_token == dest.session_token
+}
+
+/// 服务端复制，元数据和标签由 S3 一并复制
+async fn server_side_copy(
+    client: &S3Client,
+    source_bucket: &str,
+    source_key: &str,
+    dest_bucket: &str,
+    dest_key: &str,
+) -> Result<(), String> {
+    let options = super::copy::CopyOptions {
+        request_payer: client
+            .config()
+            .request_payer(source_bucket)
+            .or_else(|| client.config().request_payer(dest_bucket)),
+        ..Default::default()
+    };
+
+    super::copy::copy_object_with(
+        client.client(),
+        source_bucket,
+        source_key,
+        dest_bucket,
+        dest_key,
+        &options,
+    )
+    .await
+    .map_err(|f| format!("Failed to copy object: {}", f.message))
+}
+
+/// 通过本机中转复制对象，保留用户元数据、内容类型等头信息和标签
+async fn stream_copy(
+    source: &S3Client,
+    source_bucket: &str,
+    source_key: &str,
+    dest: &S3Client,
+    dest_bucket: &str,
+    dest_key: &str,
+) -> Result<(), String> {
+    let source_payer = source.config().request_payer(source_bucket);
+    let dest_payer = dest.config().request_payer(dest_bucket);
+
+    let (head, tagging) = tokio::join!(
+        source
+            .client()
+            .head_object()
+            .bucket(source_bucket)
+            .key(source_key)
+            .set_request_payer(source_payer.clone())
+            .send(),
+        source
+            .client()
+            .get_object_tagging()
+            .bucket(source_bucket)
+            .key(source_key)
+            .set_request_payer(source_payer.clone())
+            .send(),
+    );
+    let head = head.map_err(|e| format!("Failed to head source object: {}", e))?;
+    // 目标服务可能不支持标签，源对象没有标签时不再写入
+    let tags = tagging
+        .ok()
+        .map(|t| t.tag_set().to_vec())
+        .filter(|t| !t.is_empty());
+    let size = head.content_length().unwrap_or(0);
+
+    if size <= STREAM_MULTIPART_THRESHOLD {
+        let object = source
+            .client()
+            .get_object()
+            .bucket(source_bucket)
+            .key(source_key)
+            .set_request_payer(source_payer)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to get source object: {}", e))?;
+
+        dest.client()
+            .put_object()
+            .bucket(dest_bucket)
+            .key(dest_key)
+            .body(object.body)
+            .content_length(size)
+            .set_content_type(head.content_type().map(|s| s.to_string()))
+            .set_cache_control(head.cache_control().map(|s| s.to_string()))
+            .set_content_disposition(head.content_disposition().map(|s| s.to_string()))
+            .set_content_encoding(head.content_encoding().map(|s| s.to_string()))
+            .set_content_language(head.content_language().map(|s| s.to_string()))
+            .set_metadata(head.metadata().cloned())
+            .set_request_payer(dest_payer.clone())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to put object: {}", e))?;
+    } else {
+        let upload_id = dest
+            .client()
+            .create_multipart_upload()
+            .bucket(dest_bucket)
+            .key(dest_key)
+            .set_content_type(head.content_type().map(|s| s.to_string()))
+            .set_cache_control(head.cache_control().map(|s| s.to_string()))
+            .set_content_disposition(head.content_disposition().map(|s| s.to_string()))
+            .set_content_encoding(head.content_encoding().map(|s| s.to_string()))
+            .set_content_language(head.content_language().map(|s| s.to_string()))
+            .set_metadata(head.metadata().cloned())
+            .set_request_payer(dest_payer.clone())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to create multipart upload: {}", e))?
+            .upload_id()
+            .ok_or("Failed to get upload id")?
+            .to_string();
+
+        let result = stream_parts(
+            source,
+            source_bucket,
+            source_key,
+            dest,
+            dest_bucket,
+            dest_key,
+            &upload_id,
+            size,
+        )
+        .await;
+
+        // 任一分片失败时放弃上传，避免残留未完成的分片
+        if result.is_err() {
+            let _ = dest
+                .client()
+                .abort_multipart_upload()
+                .bucket(dest_bucket)
+                .set_request_payer(dest.config().request_payer(dest_bucket))
+                .key(dest_key)
+                .upload_id(&upload_id)
+                .send()
+                .await;
+        }
+        result?;
+    }
+
+    if let Some(tags) = tags {
+        let tagging = Tagging::builder()
+            .set_tag_set(Some(tags))
+            .build()
+            .map_err(|e| e.to_string())?;
+        dest.client()
+            .put_object_tagging()
+            .bucket(dest_bucket)
+            .key(dest_key)
+            .tagging(tagging)
+            .set_request_payer(dest_payer)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to copy object tags: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// 按范围读取源对象并逐片上传到目标，最后完成分片上传
+#[allow(clippy::too_many_arguments)]
+async fn stream_parts(
+    source: &S3Client,
+    source_bucket: &str,
+    source_key: &str,
+    dest: &S3Client,
+    dest_bucket: &str,
+    dest_key: &str,
+    upload_id: &str,
+    size: i64,
+) -> Result<(), String> {
+    // 分片数不能超过 10000
+    let part_size = STREAM_PART_SIZE.max((size + MAX_PARTS - 1) / MAX_PARTS);
+    let mut completed_parts = Vec::new();
+    let mut position = 0;
+    let mut part_number = 1;
+
+    while position < size {
+        let end = (position + part_size).min(size) - 1;
+        let object = source
+            .client()
+            .get_object()
+            .bucket(source_bucket)
+            .key(source_key)
+            .range(format!("bytes={}-{}", position, end))
+            .set_request_payer(source.config().request_payer(source_bucket))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to get source range: {}", e))?;
+
+        let part = dest
+            .client()
+            .upload_part()
+            .bucket(dest_bucket)
+            .key(dest_key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .content_length(end - position + 1)
+            .body(object.body)
+            .set_request_payer(dest.config().request_payer(dest_bucket))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to upload part {}: {}", part_number, e))?;
+
+        completed_parts.push(
+            CompletedPart::builder()
+                .part_number(part_number)
+                .set_e_tag(part.e_tag().map(|s| s.to_string()))
+                .build(),
+        );
+
+        part_number += 1;
+        position = end + 1;
+    }
+
+    dest.client()
+        .complete_multipart_upload()
+        .bucket(dest_bucket)
+        .key(dest_key)
+        .upload_id(upload_id)
+        .multipart_upload(
+            CompletedMultipartUpload::builder()
+                .set_parts(Some(completed_parts))
+                .build(),
+        )
+        .set_request_payer(dest.config().request_payer(dest_bucket))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to complete multipart upload: {}", e))?;
+
+    Ok(())
+}
+
+/// 复制对象到任意 Bucket，`dest_config` 为空时表示与源使用同一连接
+///
+/// 两端为同一服务和凭证时使用服务端复制，否则通过本机流式中转。
+#[tauri::command]
+pub async fn copy_object_between(
+    source_config: S3Config,
+    source_bucket: String,
+    source_key: String,
+    dest_config: Option<S3Config>,
+    dest_bucket: String,
+    dest_key: String,
+) -> Result<(), String> {
+    if source_bucket == dest_bucket
+        && source_key == dest_key
+        && dest_config
+            .as_ref()
+            .is_none_or(|d| same_connection(&source_config, d))
+    {
+        return Err("Source and destination are the same object".to_string());
+    }
+
+    let source = S3Client::new(source_config.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match dest_config {
+        Some(dest_config) if !same_connection(&source_config, &dest_config) => {
+            let dest = S3Client::new(dest_config)
+                .await
+                .map_err(|e| e.to_string())?;
+            stream_copy(
+                &source,
+                &source_bucket,
+                &source_key,
+                &dest,
+                &dest_bucket,
+                &dest_key,
+            )
+            .await
+        }
+        // 服务端复制由目标连接发起（区域可能不同）
+        Some(dest_config) => {
+            let dest = S3Client::new(dest_config)
+                .await
+                .map_err(|e| e.to_string())?;
+            server_side_copy(&dest, &source_bucket, &source_key, &dest_bucket, &dest_key).await
+        }
+        None => {
+            server_side_copy(
+                &source,
+                &source_bucket,
+                &source_key,
+                &dest_bucket,
+                &dest_key,
+            )
+            .await
+        }
+    }
+}
+
+/// 移动对象到任意 Bucket，复制成功后删除源对象
+#[tauri::command]
+pub async fn move_object_between(
+    source_config: S3Config,
+    source_bucket: String,
+    source_key: String,
+    dest_config: Option<S3Config>,
+    dest_bucket: String,
+    dest_key: String,
+) -> Result<(), String> {
+    copy_object_between(
+        source_config.clone(),
+        source_bucket.clone(),
+        source_key.clone(),
+        dest_config,
+        dest_bucket,
+        dest_key,
+    )
+    .await?;
+
+    let source = S3Client::new(source_config)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    source
+        .client()
+        .delete_object()
+        .bucket(&source_bucket)
+        .key(&source_key)
+        .set_request_payer(source.config().request_payer(&source_bucket))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete source object during move: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(endpoint: Option<&str>, access_key_id: &str) -> S3Config {
+        serde_json::from_value(serde_json::json!({
+            "id": access_key_id,
+            "name": access_key_id,
+            "access_key_id": access_key_id,
+            "secret_access_key": "secret",
+            "region": "us-east-1",
+            "endpoint": endpoint,
+            "session_token": null,
+            "bucket": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn same_connection_compares_endpoint_and_credentials() {
+        assert!(same_connection(&config(None, "a"), &config(None, "a")));
+        assert!(!same_connection(&config(None, "a"), &config(None, "b")));
+        assert!(!same_connection(
+            &config(None, "a"),
+            &config(Some("https://minio.example.com"), "a")
+        ));
+
+        let mut other_region = config(None, "a");
+        other_region.region = Some("eu-west-1".to_string());
+        assert!(same_connection(&config(None, "a"), &other_region));
+
+        let mut temporary = config(None, "a");
+        temporary.session_token = Some("token".to_string());
+        assert!(!same_connection(&config(None, "a"), &temporary));
+    }
+}
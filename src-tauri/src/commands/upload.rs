@@ -1,17 +1,25 @@
-use crate::s3::types::S3Config;
+use crate::s3::types::{S3Config, SseCustomerKey, SseOptions};
 use crate::s3::client::S3Client;
 use tokio::io::AsyncReadExt;
 
 /// 上传单个文件
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn upload_file(
     config: S3Config,
     bucket: String,
     key: String,
     file_path: String,
     content_type: Option<String>,
+    sse: Option<SseOptions>,
+    sse_customer_key: Option<String>,
+    acl: Option<String>,
 ) -> Result<String, String> {
     let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let sse_c = SseCustomerKey::resolve(sse_customer_key.as_deref())?;
+    if sse.is_some() && sse_c.is_some() {
+        return Err("SSE options and SSE-C key cannot be used together".to_string());
+    }
 
     // 读取文件内容
     let mut file = tokio::fs::File::open(&file_path)
@@ -29,6 +37,7 @@ pub async fn upload_file(
         .client()
         .put_object()
         .bucket(&bucket)
+        .set_request_payer(client.config().request_payer(&bucket))
         .key(&key)
         .body(aws_sdk_s3::primitives::ByteStream::from(contents));
 
@@ -44,6 +53,24 @@ pub async fn upload_file(
         }
     }
 
+    if let Some(sse) = sse {
+        builder = builder
+            .server_side_encryption(sse.server_side_encryption()?)
+            .set_ssekms_key_id(sse.kms_key_id)
+            .set_bucket_key_enabled(sse.bucket_key_enabled);
+    }
+
+    if let Some(acl) = acl {
+        builder = builder.acl(super::access::parse_object_canned_acl(&acl)?);
+    }
+
+    if let Some(k) = sse_c {
+        builder = builder
+            .sse_customer_algorithm(k.algorithm)
+            .sse_customer_key(k.key)
+            .sse_customer_key_md5(k.key_md5);
+    }
+
     builder
         .send()
         .await
@@ -62,7 +89,7 @@ pub async fn upload_files(
     let mut uploaded_keys = Vec::new();
 
     for (key, file_path) in files {
-        match upload_file(config.clone(), bucket.clone(), key, file_path, None).await {
+        match upload_file(config.clone(), bucket.clone(), key, file_path, None, None, None, None).await {
             Ok(k) => uploaded_keys.push(k),
             Err(e) => {
                 return Err(format!("Upload failed for one file: {}", e));
@@ -0,0 +1,182 @@
+use crate::s3::types::{S3Config, UsageObject, UsageStats};
+use crate::s3::client::S3Client;
+use crate::s3::{TaskManager, UsageCache};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::Arc;
+use tauri::Emitter;
+
+// 默认返回的最大对象数量
+const DEFAULT_TOP_N: usize = 20;
+
+/// 统计 Bucket 或前缀的用量
+///
+/// 分页遍历前缀下的所有对象，每页处理完成后通过 `usage-progress` 事件推送部分结果。
+/// 完整的统计结果按连接、Bucket 和前缀缓存；`use_cache` 为 true 时优先返回缓存。
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn get_prefix_usage(
+    app: tauri::AppHandle,
+    tasks: tauri::State<'_, Arc<TaskManager>>,
+    cache: tauri::State<'_, Arc<UsageCache>>,
+    config: S3Config,
+    bucket: String,
+    prefix: Option<String>,
+    task_id: String,
+    top_n: Option<usize>,
+    use_cache: Option<bool>,
+) -> Result<UsageStats, String> {
+    let prefix = prefix.unwrap_or_default();
+
+    if use_cache.unwrap_or(false) {
+        if let Some(mut cached) = cache.get(&config.id, &bucket, &prefix).await {
+            cached.task_id = task_id;
+            return Ok(cached);
+        }
+    }
+
+    let config_id = config.id.clone();
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let task = tasks.register(&task_id);
+    let top_n = top_n.unwrap_or(DEFAULT_TOP_N);
+
+    let mut stats = UsageStats {
+        task_id,
+        bucket,
+        prefix,
+        total_size: 0,
+        object_count: 0,
+        by_storage_class: HashMap::new(),
+        by_prefix: HashMap::new(),
+        largest_objects: Vec::new(),
+        completed: false,
+        computed_at: String::new(),
+    };
+    // 小顶堆，只保留最大的 top_n 个对象
+    let mut largest: BinaryHeap<Reverse<(i64, String, String)>> = BinaryHeap::new();
+    let mut continuation_token: Option<String> = None;
+
+    loop {
+        if task.is_cancelled() {
+            break;
+        }
+
+        let page = client
+            .client()
+            .list_objects_v2()
+            .bucket(&stats.bucket)
+            .set_request_payer(client.config().request_payer(&stats.bucket))
+            .prefix(&stats.prefix)
+            .set_continuation_token(continuation_token.take())
+            .send()
+            .await
+            .map_err(|e| format!("Failed to list objects: {}", e))?;
+
+        for obj in page.contents() {
+            let key = obj.key().unwrap_or("");
+            let size = obj.size().unwrap_or(0);
+            let storage_class = obj
+                .storage_class()
+                .map(|s| s.as_str().to_string())
+                .unwrap_or_else(|| "STANDARD".to_string());
+
+            stats.total_size += size;
+            stats.object_count += 1;
+
+            let class_totals = stats
+                .by_storage_class
+                .entry(storage_class.clone())
+                .or_default();
+            class_totals.size += size;
+            class_totals.count += 1;
+
+            let relative = key.strip_prefix(stats.prefix.as_str()).unwrap_or(key);
+            if let Some(pos) = relative.find('/') {
+                let sub_prefix = format!("{}{}", stats.prefix, &relative[..=pos]);
+                let prefix_totals = stats.by_prefix.entry(sub_prefix).or_default();
+                prefix_totals.size += size;
+                prefix_totals.count += 1;
+            }
+
+            if top_n > 0 {
+                largest.push(Reverse((size, key.to_string(), storage_class)));
+                if largest.len() > top_n {
+                    largest.pop();
+                }
+            }
+        }
+
+        stats.largest_objects = sorted_largest(&largest);
+        let _ = app.emit("usage-progress", &stats);
+
+        match page.next_continuation_token() {
+            Some(token) if page.is_truncated().unwrap_or(false) => {
+                continuation_token = Some(token.to_string());
+            }
+            _ => {
+                stats.completed = true;
+                break;
+            }
+        }
+    }
+
+    stats.computed_at = chrono::Utc::now().to_rfc3339();
+
+    // 只缓存完整的统计结果
+    if stats.completed {
+        cache.insert(&config_id, stats.clone()).await;
+    }
+
+    Ok(stats)
+}
+
+/// 清除 Bucket 的用量统计缓存
+#[tauri::command]
+pub async fn clear_usage_cache(
+    cache: tauri::State<'_, Arc<UsageCache>>,
+    config: S3Config,
+    bucket: String,
+) -> Result<(), String> {
+    cache.clear_bucket(&config.id, &bucket).await;
+    Ok(())
+}
+
+fn sorted_largest(heap: &BinaryHeap<Reverse<(i64, String, String)>>) -> Vec<UsageObject> {
+    let mut objects: Vec<UsageObject> = heap
+        .iter()
+        .map(|Reverse((size, key, storage_class))| UsageObject {
+            key: key.clone(),
+            size: *size,
+            storage_class: storage_class.clone(),
+        })
+        .collect();
+    objects.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.key.cmp(&b.key)));
+    objects
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_largest_orders_by_size_then_key() {
+        let mut heap = BinaryHeap::new();
+        for (size, key) in [(10, "b"), (30, "c"), (10, "a"), (20, "d")] {
+            heap.push(Reverse((size, key.to_string(), "STANDARD".to_string())));
+        }
+
+        let keys: Vec<_> = sorted_largest(&heap)
+            .into_iter()
+            .map(|o| (o.size, o.key))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                (30, "c".to_string()),
+                (20, "d".to_string()),
+                (10, "a".to_string()),
+                (10, "b".to_string()),
+            ]
+        );
+    }
+}
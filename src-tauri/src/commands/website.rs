@@ -0,0 +1,375 @@
+use crate::s3::types::{
+    is_unsupported_error, RoutingRedirect, RoutingRuleInfo, S3Config, WebsiteConfig,
+    WebsiteRedirectAll,
+};
+use crate::s3::client::S3Client;
+use aws_sdk_s3::error::ProvideErrorMetadata;
+use aws_sdk_s3::types::{
+    Condition, ErrorDocument, IndexDocument, Protocol, Redirect, RedirectAllRequestsTo,
+    RoutingRule, WebsiteConfiguration,
+};
+
+// 仍使用旧式 s3-website-<region> 域名的 AWS 区域
+const DASH_WEBSITE_REGIONS: &[&str] = &[
+    "us-east-1",
+    "us-west-1",
+    "us-west-2",
+    "ap-southeast-1",
+    "ap-southeast-2",
+    "ap-northeast-1",
+    "eu-west-1",
+    "sa-east-1",
+    "us-gov-west-1",
+];
+
+fn parse_protocol(protocol: Option<String>) -> Result<Option<Protocol>, String> {
+    match protocol.as_deref() {
+        None => Ok(None),
+        Some("http") => Ok(Some(Protocol::Http)),
+        Some("https") => Ok(Some(Protocol::Https)),
+        Some(other) => Err(format!("Unsupported redirect protocol: {}", other)),
+    }
+}
+
+/// 将 SDK 的网站配置转换为 WebsiteConfig
+pub(crate) fn website_from_sdk(
+    index_document: Option<&IndexDocument>,
+    error_document: Option<&ErrorDocument>,
+    redirect_all: Option<&RedirectAllRequestsTo>,
+    routing_rules: &[RoutingRule],
+) -> WebsiteConfig {
+    WebsiteConfig {
+        index_document: index_document.map(|d| d.suffix().to_string()),
+        error_document: error_document.map(|d| d.key().to_string()),
+        redirect_all_requests_to: redirect_all.map(|r| WebsiteRedirectAll {
+            host_name: r.host_name().to_string(),
+            protocol: r.protocol().map(|p| p.as_str().to_string()),
+        }),
+        routing_rules: routing_rules
+            .iter()
+            .map(|rule| {
+                let condition = rule.condition();
+                let redirect = rule.redirect();
+                RoutingRuleInfo {
+                    key_prefix_equals: condition
+                        .and_then(|c| c.key_prefix_equals())
+                        .map(|s| s.to_string()),
+                    http_error_code_returned_equals: condition
+                        .and_then(|c| c.http_error_code_returned_equals())
+                        .map(|s| s.to_string()),
+                    redirect: RoutingRedirect {
+                        host_name: redirect.and_then(|r| r.host_name()).map(|s| s.to_string()),
+                        http_redirect_code: redirect
+                            .and_then(|r| r.http_redirect_code())
+                            .map(|s| s.to_string()),
+                        protocol: redirect
+                            .and_then(|r| r.protocol())
+                            .map(|p| p.as_str().to_string()),
+                        replace_key_prefix_with: redirect
+                            .and_then(|r| r.replace_key_prefix_with())
+                            .map(|s| s.to_string()),
+                        replace_key_with: redirect
+                            .and_then(|r| r.replace_key_with())
+                            .map(|s| s.to_string()),
+                    },
+                }
+            })
+            .collect(),
+    }
+}
+
+/// 校验 WebsiteConfig 并转换为 SDK 类型
+pub(crate) fn website_to_sdk(website: WebsiteConfig) -> Result<WebsiteConfiguration, String> {
+    if let Some(redirect_all) = website.redirect_all_requests_to {
+        if website.index_document.is_some()
+            || website.error_document.is_some()
+            || !website.routing_rules.is_empty()
+        {
+            return Err(
+                "Redirect-all cannot be combined with index, error document or routing rules"
+                    .to_string(),
+            );
+        }
+
+        let redirect_all = RedirectAllRequestsTo::builder()
+            .host_name(redirect_all.host_name)
+            .set_protocol(parse_protocol(redirect_all.protocol)?)
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        return Ok(WebsiteConfiguration::builder()
+            .redirect_all_requests_to(redirect_all)
+            .build());
+    }
+
+    let index_document = website
+        .index_document
+        .filter(|s| !s.is_empty() && !s.contains('/'))
+        .ok_or("Index document is required and must not contain '/'")?;
+
+    let mut builder = WebsiteConfiguration::builder().index_document(
+        IndexDocument::builder()
+            .suffix(index_document)
+            .build()
+            .map_err(|e| e.to_string())?,
+    );
+
+    if let Some(error_document) = website.error_document.filter(|s| !s.is_empty()) {
+        builder = builder.error_document(
+            ErrorDocument::builder()
+                .key(error_document)
+                .build()
+                .map_err(|e| e.to_string())?,
+        );
+    }
+
+    for rule in website.routing_rules {
+        if rule.redirect.replace_key_prefix_with.is_some()
+            && rule.redirect.replace_key_with.is_some()
+        {
+            return Err(
+                "A routing rule cannot set both replace key prefix and replace key".to_string(),
+            );
+        }
+
+        let condition =
+            if rule.key_prefix_equals.is_some() || rule.http_error_code_returned_equals.is_some() {
+                Some(
+                    Condition::builder()
+                        .set_key_prefix_equals(rule.key_prefix_equals)
+                        .set_http_error_code_returned_equals(rule.http_error_code_returned_equals)
+                        .build(),
+                )
+            } else {
+                None
+            };
+
+        let redirect = Redirect::builder()
+            .set_host_name(rule.redirect.host_name)
+            .set_http_redirect_code(rule.redirect.http_redirect_code)
+            .set_protocol(parse_protocol(rule.redirect.protocol)?)
+            .set_replace_key_prefix_with(rule.redirect.replace_key_prefix_with)
+            .set_replace_key_with(rule.redirect.replace_key_with)
+            .build();
+
+        builder = builder.routing_rules(
+            RoutingRule::builder()
+                .set_condition(condition)
+                .redirect(redirect)
+                .build(),
+        );
+    }
+
+    Ok(builder.build())
+}
+
+/// 读取 Bucket 网站配置，未配置时返回 None
+pub(crate) async fn load_bucket_website(
+    client: &aws_sdk_s3::Client,
+    bucket: &str,
+) -> Result<Option<WebsiteConfig>, String> {
+    match client.get_bucket_website().bucket(bucket).send().await {
+        Ok(result) => Ok(Some(website_from_sdk(
+            result.index_document(),
+            result.error_document(),
+            result.redirect_all_requests_to(),
+            result.routing_rules(),
+        ))),
+        Err(e)
+            if e.code() == Some("NoSuchWebsiteConfiguration") || is_unsupported_error(e.code()) =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(format!("Failed to get bucket website: {}", e)),
+    }
+}
+
+/// 从 endpoint URL 中提取主机名
+fn endpoint_host(endpoint: &str) -> &str {
+    let without_scheme = endpoint
+        .split_once("://")
+        .map(|(_, rest)| rest)
+        .unwrap_or(endpoint);
+    let host = without_scheme.split('/').next().unwrap_or(without_scheme);
+    host.split(':').next().unwrap_or(host)
+}
+
+/// 是否为 AWS S3 的 endpoint（未设置 endpoint 也视为 AWS）
+fn is_aws_endpoint(endpoint: Option<&str>) -> bool {
+    endpoint
+        .map(endpoint_host)
+        .is_none_or(|host| host.ends_with(".amazonaws.com") || host.ends_with(".amazonaws.com.cn"))
+}
+
+/// 根据服务商和区域计算网站访问地址
+pub(crate) fn website_endpoint(
+    endpoint: Option<&str>,
+    region: &str,
+    bucket: &str,
+) -> Result<String, String> {
+    // AWS S3
+    if is_aws_endpoint(endpoint) {
+        let suffix = if region.starts_with("cn-") {
+            "amazonaws.com.cn"
+        } else {
+            "amazonaws.com"
+        };
+        let separator = if DASH_WEBSITE_REGIONS.contains(&region) {
+            '-'
+        } else {
+            '.'
+        };
+        return Ok(format!(
+            "http://{}.s3-website{}{}.{}",
+            bucket, separator, region, suffix
+        ));
+    }
+
+    match endpoint.map(endpoint_host) {
+        // 阿里云 OSS：静态网站使用 Bucket 的默认域名
+        Some(host) if host.ends_with(".aliyuncs.com") => Ok(format!(
+            "http://{}.{}",
+            bucket,
+            host.replace("-internal", "")
+        )),
+        // 腾讯云 COS：cos.<region> 替换为 cos-website.<region>
+        Some(host) if host.ends_with(".myqcloud.com") => {
+            let rest = host.strip_prefix("cos.").unwrap_or(host);
+            Ok(format!("http://{}.cos-website.{}", bucket, rest))
+        }
+        host => Err(format!(
+            "Static website endpoint is not available for {}",
+            host.unwrap_or_default()
+        )),
+    }
+}
+
+/// 获取 Bucket 静态网站配置
+#[tauri::command]
+pub async fn get_bucket_website(
+    config: S3Config,
+    bucket_name: String,
+) -> Result<Option<WebsiteConfig>, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    load_bucket_website(client.client(), &bucket_name).await
+}
+
+/// 设置 Bucket 静态网站配置
+#[tauri::command]
+pub async fn put_bucket_website(
+    config: S3Config,
+    bucket_name: String,
+    website: WebsiteConfig,
+) -> Result<(), String> {
+    let configuration = website_to_sdk(website)?;
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .put_bucket_website()
+        .bucket(&bucket_name)
+        .website_configuration(configuration)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to put bucket website: {}", e))?;
+
+    Ok(())
+}
+
+/// 删除 Bucket 静态网站配置
+#[tauri::command]
+pub async fn delete_bucket_website(config: S3Config, bucket_name: String) -> Result<(), String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+
+    client
+        .client()
+        .delete_bucket_website()
+        .bucket(&bucket_name)
+        .send()
+        .await
+        .map_err(|e| format!("Failed to delete bucket website: {}", e))?;
+
+    Ok(())
+}
+
+/// 计算 Bucket 静态网站访问地址
+#[tauri::command]
+pub async fn get_website_endpoint(config: S3Config, bucket_name: String) -> Result<String, String> {
+    let client = S3Client::new(config).await.map_err(|e| e.to_string())?;
+    let config = client.config();
+
+    // AWS 上以 Bucket 实际所在区域为准（包括显式配置的 AWS endpoint）
+    let region = if is_aws_endpoint(config.endpoint.as_deref()) {
+        super::bucket::get_bucket_region(client.client(), &bucket_name).await?
+    } else {
+        config
+            .region
+            .clone()
+            .unwrap_or_else(|| "us-east-1".to_string())
+    };
+
+    website_endpoint(config.endpoint.as_deref(), &region, &bucket_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_host_strips_scheme_port_and_path() {
+        assert_eq!(
+            endpoint_host("https://oss-cn-hangzhou.aliyuncs.com"),
+            "oss-cn-hangzhou.aliyuncs.com"
+        );
+        assert_eq!(endpoint_host("http://localhost:9000/minio"), "localhost");
+        assert_eq!(
+            endpoint_host("s3.eu-west-1.amazonaws.com"),
+            "s3.eu-west-1.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn website_endpoint_for_aws_regions() {
+        assert_eq!(
+            website_endpoint(None, "us-east-1", "site").unwrap(),
+            "http://site.s3-website-us-east-1.amazonaws.com"
+        );
+        assert_eq!(
+            website_endpoint(None, "eu-central-1", "site").unwrap(),
+            "http://site.s3-website.eu-central-1.amazonaws.com"
+        );
+        assert_eq!(
+            website_endpoint(None, "cn-north-1", "site").unwrap(),
+            "http://site.s3-website.cn-north-1.amazonaws.com.cn"
+        );
+        // 显式配置的 AWS endpoint 使用传入的 Bucket 区域
+        assert_eq!(
+            website_endpoint(
+                Some("https://s3.us-east-1.amazonaws.com"),
+                "ap-south-1",
+                "site"
+            )
+            .unwrap(),
+            "http://site.s3-website.ap-south-1.amazonaws.com"
+        );
+    }
+
+    #[test]
+    fn website_endpoint_for_other_providers() {
+        assert_eq!(
+            website_endpoint(
+                Some("https://oss-cn-hangzhou-internal.aliyuncs.com"),
+                "",
+                "site"
+            )
+            .unwrap(),
+            "http://site.oss-cn-hangzhou.aliyuncs.com"
+        );
+        assert_eq!(
+            website_endpoint(Some("https://cos.ap-guangzhou.myqcloud.com"), "", "site").unwrap(),
+            "http://site.cos-website.ap-guangzhou.myqcloud.com"
+        );
+        assert!(website_endpoint(Some("http://localhost:9000"), "us-east-1", "site").is_err());
+    }
+}
@@ -1,31 +1,52 @@
 mod commands;
 mod s3;
 
-use s3::ConfigManager;
+use s3::{ConfigManager, ShareRegistry, TaskManager, UsageCache};
 use std::sync::Arc;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let config_manager = ConfigManager::new();
+    let task_manager = TaskManager::new();
+    let usage_cache = UsageCache::new();
+    let share_registry = ShareRegistry::new();
 
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .manage(Arc::new(config_manager))
+        .manage(Arc::new(task_manager))
+        .manage(Arc::new(usage_cache))
+        .manage(Arc::new(share_registry))
         .invoke_handler(tauri::generate_handler![
             // Bucket commands
             commands::list_buckets,
+            commands::list_buckets_page,
+            commands::enrich_buckets,
+            commands::get_bucket_request_payment,
+            commands::put_bucket_request_payment,
             commands::create_bucket,
+            commands::create_bucket_with_options,
             commands::delete_bucket,
+            commands::force_delete_bucket,
             commands::head_bucket,
             // Object commands
             commands::list_objects,
+            commands::create_folder,
             commands::delete_object,
             commands::delete_objects,
+            commands::delete_prefix,
             commands::copy_object,
             commands::move_object,
             commands::rename_object,
+            commands::copy_prefix,
+            commands::move_prefix,
+            commands::rename_prefix,
+            commands::copy_object_between,
+            commands::move_object_between,
             commands::head_object,
+            commands::get_object_details,
+            commands::update_object_metadata,
             // Upload commands
             commands::upload_file,
             commands::upload_files,
@@ -39,6 +60,77 @@ pub fn run() {
             // Security commands
             commands::encrypt_text,
             commands::decrypt_text,
+            // Tagging commands
+            commands::get_bucket_tagging,
+            commands::put_bucket_tagging,
+            commands::delete_bucket_tagging,
+            commands::get_object_tagging,
+            commands::put_object_tagging,
+            commands::delete_object_tagging,
+            commands::apply_tags_to_prefix,
+            // Encryption commands
+            commands::get_bucket_encryption,
+            commands::put_bucket_encryption,
+            commands::delete_bucket_encryption,
+            // Object Lock commands
+            commands::get_object_lock_configuration,
+            commands::put_object_lock_configuration,
+            commands::get_object_retention,
+            commands::put_object_retention,
+            commands::get_object_legal_hold,
+            commands::put_object_legal_hold,
+            commands::delete_object_bypass_governance,
+            // Access commands
+            commands::get_public_access_block,
+            commands::put_public_access_block,
+            commands::delete_public_access_block,
+            commands::get_bucket_public_status,
+            commands::get_bucket_acl,
+            commands::put_bucket_acl,
+            commands::get_object_acl,
+            commands::put_object_acl,
+            // Website commands
+            commands::get_bucket_website,
+            commands::put_bucket_website,
+            commands::delete_bucket_website,
+            commands::get_website_endpoint,
+            // Task commands
+            commands::cancel_task,
+            // Usage commands
+            commands::get_prefix_usage,
+            commands::clear_usage_cache,
+            // Blueprint commands
+            commands::export_bucket_blueprint,
+            commands::diff_bucket_blueprints,
+            commands::diff_bucket_blueprint_live,
+            commands::apply_bucket_blueprint,
+            // Notification commands
+            commands::get_bucket_notification,
+            commands::put_bucket_notification,
+            commands::delete_bucket_notification,
+            commands::get_notification_event_names,
+            // Replication commands
+            commands::get_bucket_replication,
+            commands::put_bucket_replication,
+            commands::delete_bucket_replication,
+            // Logging commands
+            commands::get_bucket_logging,
+            commands::put_bucket_logging,
+            commands::analyze_access_logs,
+            // Intelligent-tiering commands
+            commands::list_intelligent_tiering_configurations,
+            commands::put_intelligent_tiering_configuration,
+            commands::delete_intelligent_tiering_configuration,
+            // Presign commands
+            commands::presign_get_object,
+            commands::presign_put_object,
+            commands::presign_post_policy,
+            // Share link commands
+            commands::create_share_link,
+            commands::list_share_links,
+            commands::get_share_link_url,
+            commands::delete_share_link,
+            commands::revoke_share_link,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
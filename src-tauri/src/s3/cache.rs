@@ -0,0 +1,57 @@
+use super::types::UsageStats;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+// (连接 ID, Bucket, 前缀)
+type UsageKey = (String, String, String);
+
+/// 用量统计缓存，按连接、Bucket 和前缀保存最近一次完整的统计结果
+pub struct UsageCache {
+    entries: Arc<RwLock<HashMap<UsageKey, UsageStats>>>,
+}
+
+impl UsageCache {
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// 获取缓存的统计结果
+    pub async fn get(&self, config_id: &str, bucket: &str, prefix: &str) -> Option<UsageStats> {
+        let entries = self.entries.read().await;
+        entries
+            .get(&(
+                config_id.to_string(),
+                bucket.to_string(),
+                prefix.to_string(),
+            ))
+            .cloned()
+    }
+
+    /// 保存统计结果
+    pub async fn insert(&self, config_id: &str, stats: UsageStats) {
+        let mut entries = self.entries.write().await;
+        entries.insert(
+            (
+                config_id.to_string(),
+                stats.bucket.clone(),
+                stats.prefix.clone(),
+            ),
+            stats,
+        );
+    }
+
+    /// 清除 Bucket 下所有前缀的缓存
+    pub async fn clear_bucket(&self, config_id: &str, bucket: &str) {
+        let mut entries = self.entries.write().await;
+        entries.retain(|(id, b, _), _| id != config_id || b != bucket);
+    }
+}
+
+impl Default for UsageCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
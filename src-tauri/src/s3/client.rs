@@ -44,6 +44,11 @@ impl S3Client {
             config_builder = config_builder.endpoint_url(endpoint);
         }
 
+        // 不支持 bucket.endpoint 形式虚拟主机地址的服务（如部分 MinIO 部署）需要开启，
+        // 预签名 URL 也依赖该设置生成可访问的地址
+        config_builder =
+            config_builder.force_path_style(config.force_path_style.unwrap_or(false));
+
         let sdk_config = config_builder.build();
 
         let client = Client::from_conf(sdk_config);
@@ -1,7 +1,13 @@
+pub mod cache;
 pub mod client;
 pub mod config;
+pub mod share;
+pub mod task;
 pub mod types;
 
+pub use cache::UsageCache;
 pub use client::{S3Client, S3ClientManager};
 pub use config::ConfigManager;
+pub use share::ShareRegistry;
+pub use task::TaskManager;
 pub use types::*;
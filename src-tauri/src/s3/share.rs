@@ -0,0 +1,171 @@
+use super::types::ShareRecord;
+use std::path::Path;
+use tokio::sync::Mutex;
+
+pub const SHARE_ACTIVE: &str = "active";
+pub const SHARE_EXPIRED: &str = "expired";
+pub const SHARE_REVOKED: &str = "revoked";
+
+/// 本地分享链接记录，保存在应用数据目录的 JSON 文件中
+pub struct ShareRegistry {
+    // 串行化读写，避免并发修改时丢失记录
+    lock: Mutex<()>,
+}
+
+impl ShareRegistry {
+    pub fn new() -> Self {
+        Self {
+            lock: Mutex::new(()),
+        }
+    }
+
+    async fn load(path: &Path) -> Result<Vec<ShareRecord>, String> {
+        match tokio::fs::read(path).await {
+            Ok(data) => serde_json::from_slice(&data)
+                .map_err(|e| format!("Failed to parse share registry: {}", e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(format!("Failed to read share registry: {}", e)),
+        }
+    }
+
+    async fn save(path: &Path, records: &[ShareRecord]) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|e| format!("Failed to create app data dir: {}", e))?;
+        }
+        // 已加密的记录不写入明文 URL
+        let records: Vec<ShareRecord> = records
+            .iter()
+            .cloned()
+            .map(|mut record| {
+                if record.encrypted_url.is_some() {
+                    record.url = None;
+                }
+                record
+            })
+            .collect();
+        let data = serde_json::to_vec_pretty(&records).map_err(|e| e.to_string())?;
+
+        // 先写临时文件再替换，避免写入中断导致记录损坏
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, data)
+            .await
+            .map_err(|e| format!("Failed to write share registry: {}", e))?;
+        tokio::fs::rename(&tmp, path)
+            .await
+            .map_err(|e| format!("Failed to write share registry: {}", e))
+    }
+
+    /// 读取全部记录并按当前时间计算状态
+    pub async fn list(&self, path: &Path) -> Result<Vec<ShareRecord>, String> {
+        let _guard = self.lock.lock().await;
+        let mut records = Self::load(path).await?;
+        let now = chrono::Utc::now();
+        for record in &mut records {
+            record.status = share_status(record, now).to_string();
+        }
+        Ok(records)
+    }
+
+    /// 在锁内读取、修改并写回记录
+    pub async fn update<T>(
+        &self,
+        path: &Path,
+        f: impl FnOnce(&mut Vec<ShareRecord>) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let _guard = self.lock.lock().await;
+        let mut records = Self::load(path).await?;
+        let result = f(&mut records)?;
+        Self::save(path, &records).await?;
+        Ok(result)
+    }
+}
+
+impl Default for ShareRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 计算分享状态，无法解析的过期时间视为已过期
+///
+/// 记录了临时凭证过期时间时，以链接过期时间和凭证过期时间中较早的为准。
+pub fn share_status(record: &ShareRecord, now: chrono::DateTime<chrono::Utc>) -> &'static str {
+    if record.revoked_at.is_some() {
+        return SHARE_REVOKED;
+    }
+    let active = std::iter::once(&record.expires_at)
+        .chain(record.credentials_expire_at.as_ref())
+        .all(|expires_at| {
+            matches!(chrono::DateTime::parse_from_rfc3339(expires_at), Ok(t) if t > now)
+        });
+    if active {
+        SHARE_ACTIVE
+    } else {
+        SHARE_EXPIRED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(expires_at: &str, credentials_expire_at: Option<&str>) -> ShareRecord {
+        serde_json::from_value(serde_json::json!({
+            "id": "1", "config_id": "c", "connection_name": "n", "bucket": "b", "key": "k",
+            "created_by": "me", "created_at": "2026-01-01T00:00:00Z",
+            "expires_at": expires_at, "credentials_expire_at": credentials_expire_at,
+        }))
+        .unwrap()
+    }
+
+    fn at(time: &str) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(time).unwrap().into()
+    }
+
+    #[test]
+    fn status_follows_link_expiry() {
+        let r = record("2026-01-02T00:00:00Z", None);
+        assert_eq!(share_status(&r, at("2026-01-01T12:00:00Z")), SHARE_ACTIVE);
+        assert_eq!(share_status(&r, at("2026-01-02T00:00:00Z")), SHARE_EXPIRED);
+        assert_eq!(
+            share_status(&record("soon", None), at("2026-01-01T12:00:00Z")),
+            SHARE_EXPIRED
+        );
+    }
+
+    #[test]
+    fn status_uses_earlier_credential_expiry() {
+        let r = record("2026-01-07T00:00:00Z", Some("2026-01-01T13:00:00Z"));
+        assert_eq!(share_status(&r, at("2026-01-01T12:00:00Z")), SHARE_ACTIVE);
+        assert_eq!(share_status(&r, at("2026-01-01T14:00:00Z")), SHARE_EXPIRED);
+
+        // 凭证过期晚于链接时仍以链接为准
+        let r = record("2026-01-01T13:00:00Z", Some("2026-01-07T00:00:00Z"));
+        assert_eq!(share_status(&r, at("2026-01-01T14:00:00Z")), SHARE_EXPIRED);
+    }
+
+    #[test]
+    fn revoked_wins_over_expiry() {
+        let mut r = record("2026-01-02T00:00:00Z", None);
+        r.revoked_at = Some("2026-01-01T06:00:00Z".to_string());
+        assert_eq!(share_status(&r, at("2026-01-01T12:00:00Z")), SHARE_REVOKED);
+    }
+
+    #[test]
+    fn legacy_records_keep_plaintext_url() {
+        let mut r: ShareRecord = serde_json::from_value(serde_json::json!({
+            "id": "1", "config_id": "c", "connection_name": "n", "bucket": "b", "key": "k",
+            "url": "https://example.com/k", "created_by": "me",
+            "created_at": "2026-01-01T00:00:00Z", "expires_at": "2026-01-02T00:00:00Z",
+        }))
+        .unwrap();
+        assert_eq!(r.url.as_deref(), Some("https://example.com/k"));
+        assert!(r.encrypted_url.is_none());
+
+        r.url = None;
+        let value = serde_json::to_value(&r).unwrap();
+        assert!(value.get("url").is_none());
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// 后台任务管理器，用于取消长时间运行的批量操作
+pub struct TaskManager {
+    tasks: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
+}
+
+impl TaskManager {
+    pub fn new() -> Self {
+        Self {
+            tasks: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 注册任务，返回的句柄在 drop 时自动注销
+    pub fn register(&self, task_id: &str) -> TaskHandle {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.tasks
+            .lock()
+            .unwrap()
+            .insert(task_id.to_string(), cancelled.clone());
+
+        TaskHandle {
+            tasks: self.tasks.clone(),
+            task_id: task_id.to_string(),
+            cancelled,
+        }
+    }
+
+    /// 请求取消任务，任务不存在时返回 false
+    pub fn cancel(&self, task_id: &str) -> bool {
+        match self.tasks.lock().unwrap().get(task_id) {
+            Some(cancelled) => {
+                cancelled.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Default for TaskManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 运行中任务的句柄
+pub struct TaskHandle {
+    tasks: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
+    task_id: String,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TaskHandle {
+    /// 是否已请求取消
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for TaskHandle {
+    fn drop(&mut self) {
+        let mut tasks = self.tasks.lock().unwrap();
+        // 同一个 task_id 可能已被新任务重新注册
+        if tasks
+            .get(&self.task_id)
+            .is_some_and(|c| Arc::ptr_eq(c, &self.cancelled))
+        {
+            tasks.remove(&self.task_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registered(manager: &TaskManager, task_id: &str) -> bool {
+        manager.tasks.lock().unwrap().contains_key(task_id)
+    }
+
+    #[test]
+    fn cancel_marks_registered_task() {
+        let manager = TaskManager::new();
+        let task = manager.register("a");
+        let other = manager.register("b");
+        assert!(!task.is_cancelled());
+
+        assert!(manager.cancel("a"));
+        assert!(task.is_cancelled());
+        assert!(!other.is_cancelled());
+    }
+
+    #[test]
+    fn cancel_unknown_task_returns_false() {
+        let manager = TaskManager::new();
+        assert!(!manager.cancel("missing"));
+    }
+
+    #[test]
+    fn drop_unregisters_task() {
+        let manager = TaskManager::new();
+        let task = manager.register("a");
+        assert!(registered(&manager, "a"));
+
+        drop(task);
+        assert!(!registered(&manager, "a"));
+        assert!(!manager.cancel("a"));
+    }
+
+    #[test]
+    fn drop_keeps_task_registered_again_with_same_id() {
+        let manager = TaskManager::new();
+        let old = manager.register("a");
+        let new = manager.register("a");
+
+        drop(old);
+        assert!(registered(&manager, "a"));
+        assert!(manager.cancel("a"));
+        assert!(new.is_cancelled());
+
+        drop(new);
+        assert!(!registered(&manager, "a"));
+    }
+}
@@ -12,6 +12,29 @@ pub struct S3Config {
     pub endpoint: Option<String>,
     pub session_token: Option<String>,
     pub bucket: Option<String>,
+    /// 保存的 SSE-C 密钥（Base64 编码的 256 位密钥），仅在请求中显式传入时使用
+    #[serde(default)]
+    pub sse_customer_key: Option<String>,
+    /// 对所有 Bucket 的请求声明由请求者付费
+    #[serde(default)]
+    pub requester_pays: bool,
+    /// 仅对这些 Bucket 的请求声明由请求者付费
+    #[serde(default)]
+    pub requester_pays_buckets: Vec<String>,
+    /// 使用 path-style 地址（endpoint/bucket/key），默认关闭
+    #[serde(default)]
+    pub force_path_style: Option<bool>,
+    /// 临时凭证（session_token）的过期时间，RFC 3339 格式
+    #[serde(default)]
+    pub session_expires_at: Option<String>,
+}
+
+impl S3Config {
+    /// 访问请求者付费的 Bucket 时需要发送的 x-amz-request-payer
+    pub fn request_payer(&self, bucket: &str) -> Option<aws_sdk_s3::types::RequestPayer> {
+        (self.requester_pays || self.requester_pays_buckets.iter().any(|b| b == bucket))
+            .then_some(aws_sdk_s3::types::RequestPayer::Requester)
+    }
 }
 
 /// Bucket 信息
@@ -20,6 +43,27 @@ pub struct BucketInfo {
     pub name: String,
     pub creation_date: String,
     pub region: Option<String>,
+    #[serde(default)]
+    pub is_public: Option<bool>,
+    /// Enabled、Suspended 或 Disabled（从未启用），未获取时为 None
+    #[serde(default)]
+    pub versioning: Option<String>,
+    #[serde(default)]
+    pub tags: Option<Vec<TagInfo>>,
+}
+
+/// 分页列出 Buckets 结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListBucketsResult {
+    pub buckets: Vec<BucketInfo>,
+    pub continuation_token: Option<String>,
+}
+
+/// 单个 Bucket 补充信息完成事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BucketEnriched {
+    pub task_id: String,
+    pub bucket: BucketInfo,
 }
 
 /// Object 元数据
@@ -32,6 +76,15 @@ pub struct ObjectMetadata {
     pub storage_class: String,
     pub content_type: Option<String>,
     pub is_folder: bool,
+    #[serde(default)]
+    pub tag_count: Option<i32>,
+    #[serde(default)]
+    pub server_side_encryption: Option<String>,
+    #[serde(default)]
+    pub sse_kms_key_id: Option<String>,
+    /// PENDING、COMPLETED、FAILED、REPLICA 等，仅 head_object 返回
+    #[serde(default)]
+    pub replication_status: Option<String>,
 }
 
 /// 列出 Objects 结果
@@ -61,6 +114,770 @@ pub struct DownloadProgress {
     pub percentage: f64,
 }
 
+/// 标签
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagInfo {
+    pub key: String,
+    pub value: String,
+}
+
+/// 批量操作进度
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchProgress {
+    pub task_id: String,
+    pub processed: u64,
+    pub failed: u64,
+    pub current_key: Option<String>,
+}
+
+/// 批量操作中失败的对象
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchFailure {
+    pub key: String,
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl BatchFailure {
+    /// 从 SDK 错误构造，保留服务端返回的错误码
+    pub fn from_sdk_error<E>(key: impl Into<String>, err: &E) -> Self
+    where
+        E: aws_sdk_s3::error::ProvideErrorMetadata + std::fmt::Display,
+    {
+        Self {
+            key: key.into(),
+            code: err.code().map(|c| c.to_string()),
+            message: err
+                .message()
+                .map(|m| m.to_string())
+                .unwrap_or_else(|| err.to_string()),
+        }
+    }
+}
+
+/// 服务商未实现该接口（如 MinIO、R2 不支持部分 Bucket 配置）
+pub fn is_unsupported_error(code: Option<&str>) -> bool {
+    matches!(
+        code,
+        Some("NotImplemented" | "XNotImplemented" | "MethodNotAllowed" | "NotSupported")
+    )
+}
+
+/// 区域所属的 ARN 分区，未指定区域时视为 aws
+pub fn arn_partition(region: Option<&str>) -> &'static str {
+    match region {
+        Some(r) if r.starts_with("cn-") => "aws-cn",
+        Some(r) if r.starts_with("us-gov-") => "aws-us-gov",
+        _ => "aws",
+    }
+}
+
+/// 批量操作结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchResult {
+    pub task_id: String,
+    pub succeeded: u64,
+    pub failures: Vec<BatchFailure>,
+    #[serde(default)]
+    pub cancelled: bool,
+}
+
+/// 服务端加密选项（用于上传和复制）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SseOptions {
+    /// AES256、aws:kms 或 aws:kms:dsse
+    pub algorithm: String,
+    pub kms_key_id: Option<String>,
+    pub bucket_key_enabled: Option<bool>,
+}
+
+impl SseOptions {
+    /// 校验加密算法并转换为 SDK 类型
+    pub fn server_side_encryption(
+        &self,
+    ) -> Result<aws_sdk_s3::types::ServerSideEncryption, String> {
+        parse_sse_algorithm(&self.algorithm, self.kms_key_id.is_some())
+    }
+}
+
+/// 解析服务端加密算法，KMS key 只能与 KMS 算法一起使用
+pub fn parse_sse_algorithm(
+    algorithm: &str,
+    has_kms_key: bool,
+) -> Result<aws_sdk_s3::types::ServerSideEncryption, String> {
+    use aws_sdk_s3::types::ServerSideEncryption;
+
+    let sse = match algorithm {
+        "AES256" => ServerSideEncryption::Aes256,
+        "aws:kms" => ServerSideEncryption::AwsKms,
+        "aws:kms:dsse" => ServerSideEncryption::AwsKmsDsse,
+        other => return Err(format!("Unsupported SSE algorithm: {}", other)),
+    };
+
+    if has_kms_key && sse == ServerSideEncryption::Aes256 {
+        return Err("KMS key id requires aws:kms or aws:kms:dsse".to_string());
+    }
+
+    Ok(sse)
+}
+
+/// SSE-C 客户提供的密钥
+#[derive(Debug, Clone)]
+pub struct SseCustomerKey {
+    /// SSE-C 只支持 AES256
+    pub algorithm: &'static str,
+    /// Base64 编码的密钥
+    pub key: String,
+    /// Base64 编码的密钥 MD5
+    pub key_md5: String,
+}
+
+impl SseCustomerKey {
+    /// 解析 Base64 编码的 256 位密钥
+    pub fn parse(encoded: &str) -> Result<Self, String> {
+        use base64::Engine;
+        use md5::{Digest, Md5};
+
+        let engine = base64::engine::general_purpose::STANDARD;
+        let raw = engine
+            .decode(encoded.trim())
+            .map_err(|e| format!("Invalid SSE-C key: {}", e))?;
+        if raw.len() != 32 {
+            return Err(format!(
+                "Invalid SSE-C key: expected 32 bytes, got {}",
+                raw.len()
+            ));
+        }
+
+        Ok(Self {
+            algorithm: "AES256",
+            key: engine.encode(&raw),
+            key_md5: engine.encode(Md5::digest(&raw)),
+        })
+    }
+
+    /// 解析请求中传入的密钥，为空时不发送 SSE-C 头
+    ///
+    /// 连接配置中保存的密钥不会自动附加，否则读取未使用 SSE-C 加密的对象时 S3 会返回 400，
+    /// 需要由调用方对 SSE-C 对象显式传入。
+    pub fn resolve(key: Option<&str>) -> Result<Option<Self>, String> {
+        key.filter(|k| !k.trim().is_empty())
+            .map(Self::parse)
+            .transpose()
+    }
+}
+
+/// Bucket 默认加密配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BucketEncryption {
+    pub algorithm: String,
+    pub kms_key_id: Option<String>,
+    pub bucket_key_enabled: bool,
+}
+
+/// Bucket Object Lock 配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectLockConfig {
+    pub enabled: bool,
+    /// GOVERNANCE 或 COMPLIANCE，未设置默认保留规则时为 None
+    pub mode: Option<String>,
+    pub days: Option<i32>,
+    pub years: Option<i32>,
+}
+
+/// Object 保留设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectRetentionInfo {
+    pub mode: String,
+    /// RFC 3339 格式的保留截止时间
+    pub retain_until_date: String,
+}
+
+/// 公共访问阻止设置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PublicAccessBlockSettings {
+    pub block_public_acls: bool,
+    pub ignore_public_acls: bool,
+    pub block_public_policy: bool,
+    pub restrict_public_buckets: bool,
+}
+
+/// ACL 授权
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrantInfo {
+    /// CanonicalUser、Group 或 AmazonCustomerByEmail
+    pub grantee_type: String,
+    pub grantee_id: Option<String>,
+    pub grantee_uri: Option<String>,
+    pub grantee_email: Option<String>,
+    pub grantee_display_name: Option<String>,
+    /// FULL_CONTROL、READ、WRITE、READ_ACP 或 WRITE_ACP
+    pub permission: String,
+}
+
+/// ACL 信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AclInfo {
+    pub owner_id: Option<String>,
+    pub owner_display_name: Option<String>,
+    pub grants: Vec<GrantInfo>,
+    /// 是否有授予 AllUsers 或 AuthenticatedUsers 的权限
+    #[serde(default)]
+    pub is_public: bool,
+}
+
+/// 静态网站托管配置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebsiteConfig {
+    pub index_document: Option<String>,
+    pub error_document: Option<String>,
+    /// 设置后所有请求都重定向到指定主机，不能与其他选项同时使用
+    pub redirect_all_requests_to: Option<WebsiteRedirectAll>,
+    #[serde(default)]
+    pub routing_rules: Vec<RoutingRuleInfo>,
+}
+
+/// 重定向所有请求
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebsiteRedirectAll {
+    pub host_name: String,
+    /// http 或 https
+    pub protocol: Option<String>,
+}
+
+/// 网站路由规则
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingRuleInfo {
+    pub key_prefix_equals: Option<String>,
+    pub http_error_code_returned_equals: Option<String>,
+    pub redirect: RoutingRedirect,
+}
+
+/// 路由规则的重定向目标
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoutingRedirect {
+    pub host_name: Option<String>,
+    pub http_redirect_code: Option<String>,
+    pub protocol: Option<String>,
+    pub replace_key_prefix_with: Option<String>,
+    pub replace_key_with: Option<String>,
+}
+
+/// 用量汇总（大小和对象数）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageTotals {
+    pub size: i64,
+    pub count: u64,
+}
+
+/// 用量统计中的单个对象
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageObject {
+    pub key: String,
+    pub size: i64,
+    pub storage_class: String,
+}
+
+/// Bucket 或前缀的用量统计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageStats {
+    pub task_id: String,
+    pub bucket: String,
+    pub prefix: String,
+    pub total_size: i64,
+    pub object_count: u64,
+    pub by_storage_class: HashMap<String, UsageTotals>,
+    /// 按直接子前缀汇总，直接位于当前前缀下的对象不计入
+    pub by_prefix: HashMap<String, UsageTotals>,
+    /// 按大小降序排列
+    pub largest_objects: Vec<UsageObject>,
+    /// 遍历完成为 true，进度事件和取消时的部分结果为 false
+    pub completed: bool,
+    /// RFC 3339 格式的统计时间
+    pub computed_at: String,
+}
+
+/// 创建 Bucket 的完整选项
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateBucketOptions {
+    pub region: Option<String>,
+    pub object_lock_enabled: bool,
+    pub versioning_enabled: bool,
+    pub encryption: Option<BucketEncryption>,
+    pub tags: Vec<TagInfo>,
+    pub public_access_block: Option<PublicAccessBlockSettings>,
+    pub canned_acl: Option<String>,
+}
+
+/// 生命周期转换（当前版本按天数或日期，非当前版本仅按天数）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LifecycleTransitionInfo {
+    pub days: Option<i32>,
+    /// RFC 3339 格式
+    #[serde(default)]
+    pub date: Option<String>,
+    pub storage_class: String,
+}
+
+/// 生命周期规则
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifecycleRuleInfo {
+    pub id: Option<String>,
+    pub enabled: bool,
+    pub prefix: Option<String>,
+    pub tags: Vec<TagInfo>,
+    pub object_size_greater_than: Option<i64>,
+    pub object_size_less_than: Option<i64>,
+    pub expiration_days: Option<i32>,
+    /// RFC 3339 格式
+    pub expiration_date: Option<String>,
+    pub expired_object_delete_marker: Option<bool>,
+    pub transitions: Vec<LifecycleTransitionInfo>,
+    pub noncurrent_expiration_days: Option<i32>,
+    pub newer_noncurrent_versions: Option<i32>,
+    pub noncurrent_transitions: Vec<LifecycleTransitionInfo>,
+    pub abort_incomplete_multipart_days: Option<i32>,
+}
+
+/// CORS 规则
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CorsRuleInfo {
+    pub id: Option<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_origins: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub expose_headers: Vec<String>,
+    pub max_age_seconds: Option<i32>,
+}
+
+/// Bucket 配置蓝图
+///
+/// 未配置的项为 None 或空列表，应用蓝图时会删除目标 Bucket 上对应的配置。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BucketBlueprint {
+    pub version: u32,
+    pub source_bucket: String,
+    pub exported_at: String,
+    /// Enabled 或 Suspended，从未启用时为 None
+    pub versioning: Option<String>,
+    pub lifecycle: Vec<LifecycleRuleInfo>,
+    pub policy: Option<serde_json::Value>,
+    pub cors: Vec<CorsRuleInfo>,
+    pub encryption: Option<BucketEncryption>,
+    pub tags: Vec<TagInfo>,
+    pub website: Option<WebsiteConfig>,
+    pub public_access_block: Option<PublicAccessBlockSettings>,
+    /// 导出时读取失败的配置项，key 为配置项名称；比较和应用时跳过这些项
+    pub unreadable: Vec<BatchFailure>,
+}
+
+/// 蓝图中某一项配置的差异
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlueprintDifference {
+    pub section: String,
+    pub left: serde_json::Value,
+    pub right: serde_json::Value,
+}
+
+/// 应用蓝图的结果，失败项的 key 为配置项名称
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlueprintApplyResult {
+    pub applied: Vec<String>,
+    pub failures: Vec<BatchFailure>,
+}
+
+/// Bucket 事件通知规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationRuleInfo {
+    pub id: Option<String>,
+    /// queue、topic 或 lambda；MinIO 的 webhook 等目标使用 queue
+    pub target_type: String,
+    pub target_arn: String,
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub suffix: Option<String>,
+}
+
+/// Bucket 事件通知配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationConfig {
+    pub rules: Vec<NotificationRuleInfo>,
+    pub event_bridge_enabled: bool,
+}
+
+/// 复制规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicationRuleInfo {
+    pub id: Option<String>,
+    #[serde(default)]
+    pub priority: Option<i32>,
+    pub enabled: bool,
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<TagInfo>,
+    /// 目标 Bucket 名称或 ARN（MinIO 使用 arn:minio:replication:... 形式）
+    pub destination_bucket: String,
+    #[serde(default)]
+    pub destination_account: Option<String>,
+    #[serde(default)]
+    pub storage_class: Option<String>,
+    #[serde(default)]
+    pub delete_marker_replication: bool,
+}
+
+/// Bucket 复制配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplicationConfig {
+    /// 复制使用的 IAM 角色 ARN，MinIO 可为空
+    pub role: String,
+    pub rules: Vec<ReplicationRuleInfo>,
+}
+
+/// Bucket 服务器访问日志配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BucketLoggingConfig {
+    pub target_bucket: String,
+    #[serde(default)]
+    pub target_prefix: String,
+    /// 按 账户/区域/Bucket/年/月/日 分区存放日志对象
+    #[serde(default)]
+    pub partitioned_prefix: bool,
+}
+
+/// 访问日志汇总
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccessLogTotals {
+    pub requests: u64,
+    pub bytes_sent: u64,
+}
+
+/// 按名称（Object key 或请求者）汇总的访问日志
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessLogCount {
+    pub name: String,
+    pub requests: u64,
+    pub bytes_sent: u64,
+}
+
+/// 访问日志分析结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessLogStats {
+    pub task_id: String,
+    pub bucket: String,
+    pub log_bucket: String,
+    pub log_prefix: String,
+    /// RFC 3339 格式的时间范围
+    pub start: Option<String>,
+    pub end: Option<String>,
+    pub log_objects_scanned: u64,
+    /// 读取失败的日志对象，不计入统计
+    pub failed_objects: Vec<BatchFailure>,
+    pub total_requests: u64,
+    pub total_bytes_sent: u64,
+    /// 无法解析的日志行数
+    pub parse_errors: u64,
+    pub by_operation: HashMap<String, AccessLogTotals>,
+    pub by_status: HashMap<String, AccessLogTotals>,
+    pub top_keys: Vec<AccessLogCount>,
+    pub top_requesters: Vec<AccessLogCount>,
+    /// 遍历完成为 true，进度事件和取消时的部分结果为 false
+    pub completed: bool,
+}
+
+/// 智能分层归档配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntelligentTieringConfigInfo {
+    pub id: String,
+    pub enabled: bool,
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<TagInfo>,
+    /// 连续未访问多少天后进入 Archive Access 层（90-730）
+    #[serde(default)]
+    pub archive_access_days: Option<i32>,
+    /// 连续未访问多少天后进入 Deep Archive Access 层（180-730）
+    #[serde(default)]
+    pub deep_archive_access_days: Option<i32>,
+}
+
+/// 可修改的对象系统元数据和用户元数据（x-amz-meta-*）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObjectMetadataUpdate {
+    pub content_type: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+    pub content_encoding: Option<String>,
+    pub content_language: Option<String>,
+    /// RFC 3339 或 HTTP 日期格式
+    pub expires: Option<String>,
+    /// 不含 x-amz-meta- 前缀的用户元数据，非 ASCII 的值在请求中按 RFC 2047 编码
+    pub metadata: HashMap<String, String>,
+}
+
+impl ObjectMetadataUpdate {
+    // 用户元数据总大小上限（按请求头中的键和编码后的值计算）
+    const MAX_USER_METADATA_BYTES: usize = 2048;
+
+    /// HTTP 头只能安全携带可见 ASCII 字符，其他值编码为 =?UTF-8?B?...?=
+    pub fn encode_value(value: &str) -> String {
+        use base64::Engine;
+
+        if value.bytes().all(|b| b == b' ' || b.is_ascii_graphic()) {
+            return value.to_string();
+        }
+        format!(
+            "=?UTF-8?B?{}?=",
+            base64::engine::general_purpose::STANDARD.encode(value)
+        )
+    }
+
+    /// 解码 RFC 2047 编码的元数据值（B 或 Q 编码的 UTF-8），其他值原样返回
+    pub fn decode_value(value: &str) -> String {
+        use base64::Engine;
+
+        let decode_word = |word: &str| -> Option<Vec<u8>> {
+            let inner = word.strip_prefix("=?")?.strip_suffix("?=")?;
+            let mut parts = inner.splitn(3, '?');
+            let charset = parts.next()?;
+            let encoding = parts.next()?;
+            let text = parts.next()?;
+            if !charset.eq_ignore_ascii_case("UTF-8") {
+                return None;
+            }
+            match encoding {
+                "B" | "b" => base64::engine::general_purpose::STANDARD.decode(text).ok(),
+                "Q" | "q" => {
+                    let mut bytes = Vec::with_capacity(text.len());
+                    let mut rest = text.as_bytes();
+                    while let Some((&b, tail)) = rest.split_first() {
+                        rest = tail;
+                        match b {
+                            b'_' => bytes.push(b' '),
+                            b'=' => {
+                                let hex = std::str::from_utf8(rest.get(..2)?).ok()?;
+                                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+                                rest = &rest[2..];
+                            }
+                            _ => bytes.push(b),
+                        }
+                    }
+                    Some(bytes)
+                }
+                _ => None,
+            }
+        };
+
+        // 相邻编码字之间的空白不属于内容
+        let mut decoded = Vec::new();
+        for word in value.split_whitespace() {
+            match decode_word(word) {
+                Some(bytes) => decoded.extend(bytes),
+                None => return value.to_string(),
+            }
+        }
+        if decoded.is_empty() {
+            return value.to_string();
+        }
+        String::from_utf8(decoded).unwrap_or_else(|_| value.to_string())
+    }
+
+    /// 请求中使用的用户元数据
+    pub fn encoded_metadata(&self) -> HashMap<String, String> {
+        self.metadata
+            .iter()
+            .map(|(key, value)| (key.clone(), Self::encode_value(value)))
+            .collect()
+    }
+
+    /// 校验用户元数据并解析 Expires
+    pub fn validate(&self) -> Result<Option<aws_sdk_s3::primitives::DateTime>, String> {
+        let mut total = 0;
+        for (key, value) in &self.metadata {
+            if key.is_empty()
+                || !key
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b"-_.".contains(&b))
+            {
+                return Err(format!("Invalid metadata key: {}", key));
+            }
+            total += key.len() + Self::encode_value(value).len();
+        }
+        if total > Self::MAX_USER_METADATA_BYTES {
+            return Err(format!(
+                "User metadata is too large: {} bytes (max {})",
+                total,
+                Self::MAX_USER_METADATA_BYTES
+            ));
+        }
+
+        self.expires
+            .as_deref()
+            .filter(|s| !s.trim().is_empty())
+            .map(parse_expires)
+            .transpose()
+    }
+}
+
+/// 格式化为 RFC 3339 时间（超出范围时退回为 Unix 秒数）
+pub fn format_timestamp(date: &aws_sdk_s3::primitives::DateTime) -> String {
+    date.fmt(aws_sdk_s3::primitives::DateTimeFormat::DateTime)
+        .unwrap_or_else(|_| date.secs().to_string())
+}
+
+/// 解析 Expires，支持 RFC 3339 和 HTTP 日期格式
+pub fn parse_expires(value: &str) -> Result<aws_sdk_s3::primitives::DateTime, String> {
+    use aws_sdk_s3::primitives::{DateTime, DateTimeFormat};
+
+    DateTime::from_str(value.trim(), DateTimeFormat::DateTime)
+        .or_else(|_| DateTime::from_str(value.trim(), DateTimeFormat::HttpDate))
+        .map_err(|e| format!("Invalid Expires date {}: {}", value, e))
+}
+
+/// head_object 返回的完整对象信息，时间均为 RFC 3339 格式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ObjectDetails {
+    pub key: String,
+    pub version_id: Option<String>,
+    pub size: i64,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub storage_class: String,
+    pub delete_marker: bool,
+    pub content_type: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+    pub content_encoding: Option<String>,
+    pub content_language: Option<String>,
+    /// Expires 头的原始值
+    pub expires: Option<String>,
+    pub website_redirect_location: Option<String>,
+    /// x-amz-meta-* 用户元数据（不含前缀）
+    pub metadata: HashMap<String, String>,
+    pub tag_count: Option<i32>,
+    pub server_side_encryption: Option<String>,
+    pub sse_kms_key_id: Option<String>,
+    pub bucket_key_enabled: Option<bool>,
+    pub sse_customer_algorithm: Option<String>,
+    /// 归档对象是否正在恢复，未发起恢复时为 None
+    pub restore_in_progress: Option<bool>,
+    /// 恢复副本的过期时间
+    pub restore_expiry_date: Option<String>,
+    /// ARCHIVE_ACCESS 或 DEEP_ARCHIVE_ACCESS（智能分层）
+    pub archive_status: Option<String>,
+    /// 生命周期规则计算出的过期时间
+    pub expiration_date: Option<String>,
+    pub expiration_rule_id: Option<String>,
+    pub object_lock_mode: Option<String>,
+    pub object_lock_retain_until_date: Option<String>,
+    pub object_lock_legal_hold: Option<bool>,
+    pub replication_status: Option<String>,
+    pub checksum_crc32: Option<String>,
+    pub checksum_crc32c: Option<String>,
+    pub checksum_crc64nvme: Option<String>,
+    pub checksum_sha1: Option<String>,
+    pub checksum_sha256: Option<String>,
+    /// COMPOSITE 或 FULL_OBJECT
+    pub checksum_type: Option<String>,
+    /// 分片上传的对象才有分片数
+    pub parts_count: Option<i32>,
+}
+
+/// 预签名 URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresignedUrl {
+    pub url: String,
+    pub method: String,
+    /// RFC 3339 过期时间
+    pub expires_at: String,
+    /// 使用 URL 时必须携带的请求头（例如签名中包含的 Content-Type）
+    pub headers: HashMap<String, String>,
+}
+
+/// 浏览器表单上传（POST Policy）的限制条件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PostPolicyOptions {
+    /// 固定的对象 key，与 key_prefix 二选一
+    pub key: Option<String>,
+    /// 只允许上传到该前缀下，文件名由浏览器提交（${filename}）
+    pub key_prefix: Option<String>,
+    pub expires_in_secs: u64,
+    pub content_length_min: Option<u64>,
+    pub content_length_max: Option<u64>,
+    /// Content-Type 必须以此开头，例如 image/
+    pub content_type_prefix: Option<String>,
+    pub acl: Option<String>,
+    pub success_action_redirect: Option<String>,
+}
+
+/// 表单字段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostFormField {
+    pub name: String,
+    pub value: String,
+}
+
+/// 签名后的 POST 表单
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresignedPost {
+    pub url: String,
+    /// 按顺序放在 file 字段之前的表单字段
+    pub fields: Vec<PostFormField>,
+    /// RFC 3339 过期时间
+    pub expires_at: String,
+    pub html: String,
+    pub curl: String,
+}
+
+/// 分享链接记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShareRecord {
+    pub id: String,
+    pub config_id: String,
+    pub connection_name: String,
+    pub bucket: String,
+    pub key: String,
+    #[serde(default)]
+    pub version_id: Option<String>,
+    /// 明文 URL 只在创建和获取时返回，不写入记录文件（旧版本的记录可能仍包含明文）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// 用主密码加密后的 URL
+    #[serde(default)]
+    pub encrypted_url: Option<String>,
+    pub created_by: String,
+    /// RFC 3339 时间
+    pub created_at: String,
+    pub expires_at: String,
+    /// 使用临时凭证签名时凭证的过期时间，链接会在此时提前失效
+    #[serde(default)]
+    pub credentials_expire_at: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub revoked_at: Option<String>,
+    /// rotate_key 或 policy
+    #[serde(default)]
+    pub revocation: Option<String>,
+    /// 通过更换 key 撤销时对象的新 key
+    #[serde(default)]
+    pub rotated_to: Option<String>,
+    /// active、expired 或 revoked，列出时根据当前时间计算
+    #[serde(default)]
+    pub status: String,
+}
+
 /// 错误类型
 #[derive(Debug, thiserror::Error)]
 pub enum S3Error {
@@ -94,3 +911,105 @@ impl Serialize for S3Error {
         serializer.serialize_str(&self.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SSE_C_KEY: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+
+    #[test]
+    fn sse_customer_key_parse_computes_md5() {
+        let key = SseCustomerKey::parse(&format!("  {}\n", SSE_C_KEY)).unwrap();
+        assert_eq!(key.algorithm, "AES256");
+        assert_eq!(key.key, SSE_C_KEY);
+        assert_eq!(key.key_md5, "tP/LI3N87DFaSk0aoqYgzg==");
+    }
+
+    #[test]
+    fn sse_customer_key_parse_rejects_invalid_keys() {
+        assert!(SseCustomerKey::parse("not base64!").is_err());
+        // 16 字节（AES-128）的密钥
+        assert!(SseCustomerKey::parse("AAECAwQFBgcICQoLDA0ODw==").is_err());
+    }
+
+    #[test]
+    fn sse_customer_key_resolve_is_opt_in() {
+        assert!(SseCustomerKey::resolve(None).unwrap().is_none());
+        assert!(SseCustomerKey::resolve(Some("  ")).unwrap().is_none());
+        assert!(SseCustomerKey::resolve(Some(SSE_C_KEY)).unwrap().is_some());
+    }
+
+    #[test]
+    fn metadata_values_are_rfc2047_encoded() {
+        assert_eq!(
+            ObjectMetadataUpdate::encode_value("plain value"),
+            "plain value"
+        );
+        assert_eq!(
+            ObjectMetadataUpdate::encode_value("中文"),
+            "=?UTF-8?B?5Lit5paH?="
+        );
+        assert_eq!(
+            ObjectMetadataUpdate::encode_value("a\nb"),
+            "=?UTF-8?B?YQpi?="
+        );
+    }
+
+    #[test]
+    fn metadata_values_are_decoded() {
+        assert_eq!(
+            ObjectMetadataUpdate::decode_value("=?UTF-8?B?5Lit5paH?="),
+            "中文"
+        );
+        assert_eq!(
+            ObjectMetadataUpdate::decode_value("=?utf-8?q?caf=C3=A9_au_lait?="),
+            "café au lait"
+        );
+        assert_eq!(
+            ObjectMetadataUpdate::decode_value("=?UTF-8?B?5Lit?= =?UTF-8?B?5paH?="),
+            "中文"
+        );
+        // 非编码值和不支持的字符集原样返回
+        assert_eq!(
+            ObjectMetadataUpdate::decode_value("plain value"),
+            "plain value"
+        );
+        assert_eq!(
+            ObjectMetadataUpdate::decode_value("=?ISO-8859-1?Q?a?="),
+            "=?ISO-8859-1?Q?a?="
+        );
+        assert_eq!(
+            ObjectMetadataUpdate::decode_value("=?UTF-8?B?***?="),
+            "=?UTF-8?B?***?="
+        );
+    }
+
+    #[test]
+    fn metadata_validation_counts_encoded_size() {
+        let mut update = ObjectMetadataUpdate::default();
+        update
+            .metadata
+            .insert("note".to_string(), "中文".to_string());
+        assert!(update.validate().is_ok());
+        assert_eq!(update.encoded_metadata()["note"], "=?UTF-8?B?5Lit5paH?=");
+
+        // 600 个汉字为 1800 字节，编码后超过 2KB
+        update.metadata.insert("note".to_string(), "中".repeat(600));
+        assert!(update.validate().is_err());
+
+        update.metadata.clear();
+        update
+            .metadata
+            .insert("bad key".to_string(), "v".to_string());
+        assert!(update.validate().is_err());
+    }
+
+    #[test]
+    fn arn_partition_from_region() {
+        assert_eq!(arn_partition(Some("cn-northwest-1")), "aws-cn");
+        assert_eq!(arn_partition(Some("us-gov-east-1")), "aws-us-gov");
+        assert_eq!(arn_partition(Some("eu-central-1")), "aws");
+        assert_eq!(arn_partition(None), "aws");
+    }
+}
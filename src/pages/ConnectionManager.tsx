@@ -10,6 +10,7 @@ import {
   Popconfirm,
   message,
   Card,
+  Switch,
 } from 'antd'
 import { PlusOutlined, DeleteOutlined, EditOutlined, CheckOutlined } from '@ant-design/icons'
 import { useConfigStore } from '../store'
@@ -191,9 +192,26 @@ export const ConnectionManager: React.FC = () => {
             <Input placeholder="https://s3.amazonaws.com 或自定义 endpoint" />
           </Form.Item>
 
+          <Form.Item
+            label="使用 Path-style 地址"
+            name="force_path_style"
+            valuePropName="checked"
+            tooltip="不支持 bucket.endpoint 形式地址的服务（如部分 MinIO 部署）需要开启"
+          >
+            <Switch />
+          </Form.Item>
+
           <Form.Item label="Session Token (可选)" name="session_token">
             <Input.TextArea placeholder="Session Token" rows={2} />
           </Form.Item>
+
+          <Form.Item
+            label="Session 过期时间 (可选)"
+            name="session_expires_at"
+            tooltip="使用临时凭证时填写，分享链接会在凭证过期时提前失效"
+          >
+            <Input placeholder="2026-01-01T00:00:00Z" />
+          </Form.Item>
         </Form>
       </Modal>
     </Card>
@@ -12,6 +12,7 @@ import {
 } from 'antd'
 import {
   FolderOutlined,
+  FolderAddOutlined,
   FileOutlined,
   DeleteOutlined,
   DownloadOutlined,
@@ -26,8 +27,9 @@ import { useConfigStore, useBucketStore } from '../store'
 import { s3Service } from '../services/s3'
 import { UploadModal } from '../components/UploadModal'
 import { FilePreview } from '../components/FilePreview'
-import type { ObjectMetadata } from '../types'
+import type { ObjectMetadata, BatchResult } from '../types'
 import dayjs from 'dayjs'
+import { v4 as uuidv4 } from 'uuid'
 
 const { Search } = Input
 const { Text } = Typography
@@ -52,6 +54,10 @@ export const FileBrowser: React.FC = () => {
   const [renameKey, setRenameKey] = useState('')
   const [newName, setNewName] = useState('')
 
+  // 新建文件夹
+  const [folderModalOpen, setFolderModalOpen] = useState(false)
+  const [folderName, setFolderName] = useState('')
+
   // 正在处理的操作 key => 操作类型
   const [processingKeys, setProcessingKeys] = useState<Record<string, 'delete' | 'rename' | 'download'>>({})
 
@@ -78,13 +84,12 @@ export const FileBrowser: React.FC = () => {
         1000
       )
 
-      const filteredObjects = result.objects.filter(
-        (obj) => obj.key !== currentPrefix?.replace(/\/$/, '')
-      )
+      // 目录条目（公共前缀和目录标记）由后端合并
+      const files = result.objects.filter((obj) => !obj.is_folder)
 
-      setObjects(filteredObjects)
-      setFilteredObjects(filteredObjects)
-      setFolders(result.common_prefixes)
+      setObjects(files)
+      setFilteredObjects(files)
+      setFolders(result.objects.filter((obj) => obj.is_folder).map((obj) => obj.key))
     } catch (error: any) {
       message.error(`获取对象列表失败: ${error}`)
     } finally {
@@ -140,6 +145,39 @@ export const FileBrowser: React.FC = () => {
     }
   }
 
+  // 汇报文件夹批量操作的结果，部分对象失败时提示失败数量
+  const reportBatchResult = (action: string, result: BatchResult) => {
+    if (result.failures.length > 0) {
+      message.warning(`${action}完成，${result.failures.length} 个对象失败: ${result.failures[0].message}`)
+    } else {
+      message.success(`${action}成功`)
+    }
+  }
+
+  // 删除文件夹：删除前缀下的所有对象，目录标记最后删除
+  const handleDeleteFolder = async (prefix: string) => {
+    const config = await getActiveConfig()
+    if (!config || !currentBucket) return
+
+    setProcessingKeys(prev => ({ ...prev, [prefix]: 'delete' }))
+    const hide = message.loading(`正在删除文件夹 "${prefix.split('/').filter(Boolean).pop()}"...`, 0)
+    try {
+      const result = await s3Service.deletePrefix(config, currentBucket, prefix, uuidv4())
+      hide()
+      reportBatchResult('删除', result)
+      fetchObjects()
+    } catch (error: any) {
+      hide()
+      message.error(`删除失败: ${error}`)
+    } finally {
+      setProcessingKeys(prev => {
+        const newKeys = { ...prev }
+        delete newKeys[prefix]
+        return newKeys
+      })
+    }
+  }
+
   const handleRename = async () => {
     if (!newName.trim()) {
       message.warning('请输入新名称')
@@ -149,20 +187,26 @@ export const FileBrowser: React.FC = () => {
     const config = await getActiveConfig()
     if (!config || !currentBucket || !renameKey) return
 
-    const newKey = currentPrefix
-      ? currentPrefix + newName
-      : newName
+    // 文件夹的 key 以 / 结尾，重命名时移动整个前缀
+    const isFolder = renameKey.endsWith('/')
+    const newKey = (currentPrefix || '') + newName + (isFolder ? '/' : '')
 
     // 关闭弹窗并开始重命名
     setRenameModalOpen(false)
     setProcessingKeys(prev => ({ ...prev, [renameKey]: 'rename' }))
 
-    const hide = message.loading(`正在重命名 "${renameKey.split('/').pop()}" 为 "${newName}"...`, 0)
+    const hide = message.loading(`正在重命名 "${renameKey.split('/').filter(Boolean).pop()}" 为 "${newName}"...`, 0)
 
     try {
-      await s3Service.renameObject(config, currentBucket, renameKey, newKey)
-      hide()
-      message.success('重命名成功')
+      if (isFolder) {
+        const result = await s3Service.renamePrefix(config, currentBucket, renameKey, newKey, uuidv4())
+        hide()
+        reportBatchResult('重命名', result)
+      } else {
+        await s3Service.renameObject(config, currentBucket, renameKey, newKey)
+        hide()
+        message.success('重命名成功')
+      }
       setNewName('')
       fetchObjects()
     } catch (error: any) {
@@ -177,6 +221,26 @@ export const FileBrowser: React.FC = () => {
     }
   }
 
+  const handleCreateFolder = async () => {
+    if (!folderName.trim()) {
+      message.warning('请输入文件夹名称')
+      return
+    }
+
+    const config = await getActiveConfig()
+    if (!config || !currentBucket) return
+
+    try {
+      await s3Service.createFolder(config, currentBucket, (currentPrefix || '') + folderName)
+      message.success('文件夹创建成功')
+      setFolderModalOpen(false)
+      setFolderName('')
+      fetchObjects()
+    } catch (error: any) {
+      message.error(`创建文件夹失败: ${error}`)
+    }
+  }
+
   const handleDownload = async (key: string) => {
     const config = await getActiveConfig()
     if (!config || !currentBucket) return
@@ -348,8 +412,49 @@ export const FileBrowser: React.FC = () => {
       title: '操作',
       key: 'action',
       render: (_: any, record: any) => {
-        if (record.is_folder) return null
         const isProcessing = processingKeys[record.key]
+        if (record.is_folder) {
+          const name = record.key.split('/').filter(Boolean).pop() || record.key
+          // 阻止点击冒泡到行，避免进入文件夹
+          return (
+            <Space size="small" onClick={(e) => e.stopPropagation()}>
+              <Button
+                type="link"
+                size="small"
+                icon={<EditOutlined />}
+                onClick={() => {
+                  setRenameKey(record.key)
+                  setNewName(name)
+                  setRenameModalOpen(true)
+                }}
+                disabled={!!isProcessing}
+                loading={isProcessing === 'rename'}
+              >
+                重命名
+              </Button>
+              <Popconfirm
+                title="确认删除"
+                description={`确定要删除文件夹 "${name}" 及其中的所有文件吗？此操作不可恢复！`}
+                onConfirm={() => handleDeleteFolder(record.key)}
+                okText="删除"
+                cancelText="取消"
+                okButtonProps={{ danger: true }}
+                disabled={!!isProcessing}
+              >
+                <Button
+                  type="link"
+                  size="small"
+                  danger
+                  icon={<DeleteOutlined />}
+                  disabled={!!isProcessing}
+                  loading={isProcessing === 'delete'}
+                >
+                  删除
+                </Button>
+              </Popconfirm>
+            </Space>
+          )
+        }
         return (
           <Space size="small">
             {(isImageFile(record.key) || isTextFile(record.key)) && (
@@ -448,6 +553,12 @@ export const FileBrowser: React.FC = () => {
               下载选中 ({selectedKeys.length})
             </Button>
           )}
+          <Button
+            icon={<FolderAddOutlined />}
+            onClick={() => setFolderModalOpen(true)}
+          >
+            新建文件夹
+          </Button>
           <Button
             type="primary"
             icon={<UploadOutlined />}
@@ -534,6 +645,23 @@ export const FileBrowser: React.FC = () => {
           onPressEnter={handleRename}
         />
       </Modal>
+
+      <Modal
+        title="新建文件夹"
+        open={folderModalOpen}
+        onOk={handleCreateFolder}
+        onCancel={() => {
+          setFolderModalOpen(false)
+          setFolderName('')
+        }}
+      >
+        <Input
+          value={folderName}
+          onChange={(e) => setFolderName(e.target.value)}
+          placeholder="输入文件夹名称"
+          onPressEnter={handleCreateFolder}
+        />
+      </Modal>
     </div>
   )
 }
@@ -4,8 +4,36 @@ import type {
   BucketInfo,
   ObjectMetadata,
   ListObjectsResult,
+  TagInfo,
+  BatchResult,
+  SseOptions,
+  BucketEncryption,
+  ObjectLockConfig,
+  ObjectRetentionInfo,
+  PublicAccessBlockSettings,
+  AclInfo,
+  WebsiteConfig,
+  UsageStats,
+  CreateBucketOptions,
+  BucketBlueprint,
+  BlueprintDifference,
+  BlueprintApplyResult,
+  BlueprintSection,
+  NotificationConfig,
+  ReplicationConfig,
+  BucketLoggingConfig,
+  AccessLogStats,
+  ListBucketsResult,
+  IntelligentTieringConfigInfo,
+  ObjectMetadataUpdate,
+  ObjectDetails,
+  PresignedUrl,
+  PostPolicyOptions,
+  PresignedPost,
+  ShareRecord,
 } from '../types'
 import { cacheService, generateCacheKey } from './cache'
+import { securityService } from './security'
 
 // 缓存配置
 const CACHE_TTL = {
@@ -37,9 +65,43 @@ export const s3Service = {
     return result
   },
 
+  // 分页列出 Buckets（支持前缀过滤）
+  async listBucketsPage(
+    config: S3Config,
+    options: { prefix?: string; continuationToken?: string; maxBuckets?: number } = {}
+  ): Promise<ListBucketsResult> {
+    return await invoke<ListBucketsResult>('list_buckets_page', { config, ...options })
+  },
+
+  // 并发补充 Buckets 的区域、版本控制、标签和公开状态（每个完成后通过 bucket-enriched 事件推送）
+  async enrichBuckets(
+    config: S3Config,
+    buckets: BucketInfo[],
+    taskId: string
+  ): Promise<BucketInfo[]> {
+    return await invoke<BucketInfo[]>('enrich_buckets', { config, buckets, taskId })
+  },
+
   // 创建 Bucket
-  async createBucket(config: S3Config, bucketName: string, region?: string): Promise<void> {
-    await invoke<void>('create_bucket', { config, bucketName, region })
+  async createBucket(
+    config: S3Config,
+    bucketName: string,
+    region?: string,
+    objectLockEnabled?: boolean
+  ): Promise<void> {
+    await invoke<void>('create_bucket', { config, bucketName, region, objectLockEnabled })
+
+    // 清除 bucket 列表缓存
+    cacheService.delete(generateCacheKey.listBuckets(config.id))
+  },
+
+  // 使用完整选项创建 Bucket（版本控制、加密、标签、公共访问阻止和 ACL）
+  async createBucketWithOptions(
+    config: S3Config,
+    bucketName: string,
+    options: CreateBucketOptions
+  ): Promise<void> {
+    await invoke<void>('create_bucket_with_options', { config, bucketName, options })
 
     // 清除 bucket 列表缓存
     cacheService.delete(generateCacheKey.listBuckets(config.id))
@@ -54,6 +116,32 @@ export const s3Service = {
     cacheService.clearBucket(bucketName)
   },
 
+  // 强制删除非空 Bucket（confirmation 必须与 Bucket 名称一致，进度通过 force-delete-progress 事件推送）
+  async forceDeleteBucket(
+    config: S3Config,
+    bucketName: string,
+    confirmation: string,
+    taskId: string
+  ): Promise<BatchResult> {
+    const result = await invoke<BatchResult>('force_delete_bucket', {
+      config,
+      bucketName,
+      confirmation,
+      taskId,
+    })
+
+    // 清除 bucket 列表缓存和该 bucket 的所有缓存
+    cacheService.delete(generateCacheKey.listBuckets(config.id))
+    cacheService.clearBucket(bucketName)
+
+    return result
+  },
+
+  // 取消正在运行的批量任务
+  async cancelTask(taskId: string): Promise<boolean> {
+    return await invoke<boolean>('cancel_task', { taskId })
+  },
+
   // 检查 Bucket 是否存在
   async headBucket(config: S3Config, bucketName: string): Promise<boolean> {
     return await invoke<boolean>('head_bucket', { config, bucketName })
@@ -106,6 +194,16 @@ export const s3Service = {
     return result
   },
 
+  // 创建目录（写入以 / 结尾的空标记对象），返回目录 key
+  async createFolder(config: S3Config, bucket: string, key: string): Promise<string> {
+    const folder = await invoke<string>('create_folder', { config, bucket, key })
+
+    // 清除父目录缓存
+    this._clearListCacheForPrefix(bucket, folder.replace(/\/$/, ''))
+
+    return folder
+  },
+
   // 删除 Object
   async deleteObject(config: S3Config, bucket: string, key: string): Promise<void> {
     await invoke<void>('delete_object', { config, bucket, key })
@@ -114,7 +212,7 @@ export const s3Service = {
     this._clearListCacheForPrefix(bucket, key)
   },
 
-  // 批量删除 Objects
+  // 批量删除 Objects，返回删除失败的 key；请求本身失败时抛出错误
   async deleteObjects(config: S3Config, bucket: string, keys: string[]): Promise<string[]> {
     const result = await invoke<string[]>('delete_objects', { config, bucket, keys })
 
@@ -124,28 +222,202 @@ export const s3Service = {
     return result
   },
 
+  // 递归删除前缀下的所有 Objects，进度通过 delete-prefix-progress 事件推送
+  async deletePrefix(
+    config: S3Config,
+    bucket: string,
+    prefix: string,
+    taskId: string
+  ): Promise<BatchResult> {
+    const result = await invoke<BatchResult>('delete_prefix', { config, bucket, prefix, taskId })
+
+    this._clearListCacheForPrefix(bucket, prefix)
+    // 目录标记删除后父目录的列表也会变化
+    this._clearListCacheForPrefix(bucket, prefix.replace(/\/$/, ''))
+
+    return result
+  },
+
+  // 复制前缀下的所有对象，重新执行相同参数即可续传
+  async copyPrefix(
+    config: S3Config,
+    bucket: string,
+    sourcePrefix: string,
+    destPrefix: string,
+    taskId: string
+  ): Promise<BatchResult> {
+    const result = await invoke<BatchResult>('copy_prefix', {
+      config,
+      bucket,
+      sourcePrefix,
+      destPrefix,
+      taskId,
+    })
+
+    this._clearListCacheForPrefix(bucket, destPrefix)
+
+    return result
+  },
+
+  // 移动前缀下的所有对象，源对象在复制校验通过后才会删除
+  async movePrefix(
+    config: S3Config,
+    bucket: string,
+    sourcePrefix: string,
+    destPrefix: string,
+    taskId: string
+  ): Promise<BatchResult> {
+    const result = await invoke<BatchResult>('move_prefix', {
+      config,
+      bucket,
+      sourcePrefix,
+      destPrefix,
+      taskId,
+    })
+
+    this._clearListCacheForPrefix(bucket, sourcePrefix)
+    this._clearListCacheForPrefix(bucket, destPrefix)
+
+    return result
+  },
+
+  // 重命名文件夹
+  async renamePrefix(
+    config: S3Config,
+    bucket: string,
+    oldPrefix: string,
+    newPrefix: string,
+    taskId: string
+  ): Promise<BatchResult> {
+    const result = await invoke<BatchResult>('rename_prefix', {
+      config,
+      bucket,
+      oldPrefix,
+      newPrefix,
+      taskId,
+    })
+
+    this._clearListCacheForPrefix(bucket, oldPrefix)
+    this._clearListCacheForPrefix(bucket, newPrefix)
+
+    return result
+  },
+
+  // 复制 Object 到任意 Bucket，destConfig 为空时使用源连接
+  async copyObjectBetween(
+    sourceConfig: S3Config,
+    sourceBucket: string,
+    sourceKey: string,
+    destConfig: S3Config | null,
+    destBucket: string,
+    destKey: string
+  ): Promise<void> {
+    await invoke<void>('copy_object_between', {
+      sourceConfig,
+      sourceBucket,
+      sourceKey,
+      destConfig,
+      destBucket,
+      destKey,
+    })
+
+    this._clearListCacheForPrefix(destBucket, destKey)
+  },
+
+  // 移动 Object 到任意 Bucket
+  async moveObjectBetween(
+    sourceConfig: S3Config,
+    sourceBucket: string,
+    sourceKey: string,
+    destConfig: S3Config | null,
+    destBucket: string,
+    destKey: string
+  ): Promise<void> {
+    await invoke<void>('move_object_between', {
+      sourceConfig,
+      sourceBucket,
+      sourceKey,
+      destConfig,
+      destBucket,
+      destKey,
+    })
+
+    this._clearListCacheForPrefix(sourceBucket, sourceKey)
+    this._clearListCacheForPrefix(destBucket, destKey)
+  },
+
   // 复制 Object
   async copyObject(
     config: S3Config,
     bucket: string,
     sourceKey: string,
-    destKey: string
+    destKey: string,
+    sse?: SseOptions,
+    sseCustomerKey?: string,
+    sourceSseCustomerKey?: string,
+    sourceVersionId?: string
   ): Promise<void> {
-    await invoke<void>('copy_object', { config, bucket, sourceKey, destKey })
+    await invoke<void>('copy_object', {
+      config,
+      bucket,
+      sourceKey,
+      destKey,
+      sse,
+      sseCustomerKey,
+      sourceSseCustomerKey,
+      sourceVersionId,
+    })
 
     // 清除相关缓存
     this._clearListCacheForPrefix(bucket, sourceKey)
     this._clearListCacheForPrefix(bucket, destKey)
   },
 
+  // 获取 Object 完整信息
+  async getObjectDetails(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    versionId?: string,
+    sseCustomerKey?: string
+  ): Promise<ObjectDetails> {
+    return await invoke<ObjectDetails>('get_object_details', {
+      config,
+      bucket,
+      key,
+      versionId,
+      sseCustomerKey,
+    })
+  },
+
+  // 修改 Object 元数据（通过复制到自身实现）
+  async updateObjectMetadata(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    metadata: ObjectMetadataUpdate,
+    sseCustomerKey?: string
+  ): Promise<void> {
+    await invoke<void>('update_object_metadata', {
+      config,
+      bucket,
+      key,
+      metadata,
+      sseCustomerKey,
+    })
+
+    this._clearListCacheForPrefix(bucket, key)
+  },
+
   // 移动 Object
   async moveObject(
     config: S3Config,
     bucket: string,
     sourceKey: string,
-    destKey: string
+    destKey: string,
+    sseCustomerKey?: string
   ): Promise<void> {
-    await invoke<void>('move_object', { config, bucket, sourceKey, destKey })
+    await invoke<void>('move_object', { config, bucket, sourceKey, destKey, sseCustomerKey })
 
     // 清除相关缓存
     this._clearListCacheForPrefix(bucket, sourceKey)
@@ -157,9 +429,10 @@ export const s3Service = {
     config: S3Config,
     bucket: string,
     oldKey: string,
-    newKey: string
+    newKey: string,
+    sseCustomerKey?: string
   ): Promise<void> {
-    await invoke<void>('rename_object', { config, bucket, oldKey, newKey })
+    await invoke<void>('rename_object', { config, bucket, oldKey, newKey, sseCustomerKey })
 
     // 清除相关缓存
     this._clearListCacheForPrefix(bucket, oldKey)
@@ -170,9 +443,10 @@ export const s3Service = {
   async headObject(
     config: S3Config,
     bucket: string,
-    key: string
+    key: string,
+    sseCustomerKey?: string
   ): Promise<ObjectMetadata> {
-    return await invoke<ObjectMetadata>('head_object', { config, bucket, key })
+    return await invoke<ObjectMetadata>('head_object', { config, bucket, key, sseCustomerKey })
   },
 
   // 上传单个文件
@@ -181,7 +455,10 @@ export const s3Service = {
     bucket: string,
     key: string,
     filePath: string,
-    contentType?: string
+    contentType?: string,
+    sse?: SseOptions,
+    sseCustomerKey?: string,
+    acl?: string
   ): Promise<string> {
     const result = await invoke<string>('upload_file', {
       config,
@@ -189,6 +466,9 @@ export const s3Service = {
       key,
       filePath,
       contentType,
+      sse,
+      sseCustomerKey,
+      acl,
     })
 
     // 清除相关缓存
@@ -217,7 +497,10 @@ export const s3Service = {
     bucket: string,
     key: string,
     filePath: string,
-    partSizeMb?: number
+    partSizeMb?: number,
+    sse?: SseOptions,
+    sseCustomerKey?: string,
+    acl?: string
   ): Promise<string> {
     const result = await invoke<string>('upload_multipart', {
       config,
@@ -225,6 +508,9 @@ export const s3Service = {
       key,
       filePath,
       partSizeMb,
+      sse,
+      sseCustomerKey,
+      acl,
     })
 
     // 清除相关缓存
@@ -253,13 +539,15 @@ export const s3Service = {
     config: S3Config,
     bucket: string,
     key: string,
-    savePath: string
+    savePath: string,
+    sseCustomerKey?: string
   ): Promise<string> {
     return await invoke<string>('download_file', {
       config,
       bucket,
       key,
       savePath,
+      sseCustomerKey,
     })
   },
 
@@ -276,18 +564,585 @@ export const s3Service = {
   async getFileContent(
     config: S3Config,
     bucket: string,
-    key: string
+    key: string,
+    sseCustomerKey?: string
   ): Promise<string> {
-    return await invoke<string>('get_file_content', { config, bucket, key })
+    return await invoke<string>('get_file_content', { config, bucket, key, sseCustomerKey })
   },
 
   // 获取文件的二进制数据（Base64 编码，用于图片预览）
   async getFileBytes(
     config: S3Config,
     bucket: string,
-    key: string
+    key: string,
+    sseCustomerKey?: string
   ): Promise<string> {
-    return await invoke<string>('get_file_bytes', { config, bucket, key })
+    return await invoke<string>('get_file_bytes', { config, bucket, key, sseCustomerKey })
+  },
+
+  // 获取 Bucket 标签
+  async getBucketTagging(config: S3Config, bucketName: string): Promise<TagInfo[]> {
+    return await invoke<TagInfo[]>('get_bucket_tagging', { config, bucketName })
+  },
+
+  // 设置 Bucket 标签
+  async putBucketTagging(config: S3Config, bucketName: string, tags: TagInfo[]): Promise<void> {
+    await invoke<void>('put_bucket_tagging', { config, bucketName, tags })
+  },
+
+  // 删除 Bucket 标签
+  async deleteBucketTagging(config: S3Config, bucketName: string): Promise<void> {
+    await invoke<void>('delete_bucket_tagging', { config, bucketName })
+  },
+
+  // 获取 Object 标签
+  async getObjectTagging(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    versionId?: string
+  ): Promise<TagInfo[]> {
+    return await invoke<TagInfo[]>('get_object_tagging', { config, bucket, key, versionId })
+  },
+
+  // 设置 Object 标签
+  async putObjectTagging(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    tags: TagInfo[],
+    versionId?: string
+  ): Promise<void> {
+    await invoke<void>('put_object_tagging', { config, bucket, key, tags, versionId })
+  },
+
+  // 删除 Object 标签
+  async deleteObjectTagging(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    versionId?: string
+  ): Promise<void> {
+    await invoke<void>('delete_object_tagging', { config, bucket, key, versionId })
+  },
+
+  // 为前缀下的所有 Object 设置标签（进度通过 tagging-progress 事件推送）
+  async applyTagsToPrefix(
+    config: S3Config,
+    bucket: string,
+    prefix: string,
+    tags: TagInfo[],
+    taskId: string
+  ): Promise<BatchResult> {
+    return await invoke<BatchResult>('apply_tags_to_prefix', {
+      config,
+      bucket,
+      prefix,
+      tags,
+      taskId,
+    })
+  },
+
+  // 获取 Bucket 默认加密配置（未配置时返回 null）
+  async getBucketEncryption(
+    config: S3Config,
+    bucketName: string
+  ): Promise<BucketEncryption | null> {
+    return await invoke<BucketEncryption | null>('get_bucket_encryption', { config, bucketName })
+  },
+
+  // 设置 Bucket 默认加密配置
+  async putBucketEncryption(
+    config: S3Config,
+    bucketName: string,
+    encryption: BucketEncryption
+  ): Promise<void> {
+    await invoke<void>('put_bucket_encryption', { config, bucketName, encryption })
+  },
+
+  // 删除 Bucket 默认加密配置
+  async deleteBucketEncryption(config: S3Config, bucketName: string): Promise<void> {
+    await invoke<void>('delete_bucket_encryption', { config, bucketName })
+  },
+
+  // 获取 Bucket Object Lock 配置
+  async getObjectLockConfiguration(config: S3Config, bucketName: string): Promise<ObjectLockConfig> {
+    return await invoke<ObjectLockConfig>('get_object_lock_configuration', { config, bucketName })
+  },
+
+  // 设置 Bucket 默认保留规则（Object Lock 无法关闭，enabled 必须为 true）
+  async putObjectLockConfiguration(
+    config: S3Config,
+    bucketName: string,
+    lock: ObjectLockConfig
+  ): Promise<void> {
+    await invoke<void>('put_object_lock_configuration', { config, bucketName, lock })
+  },
+
+  // 获取 Object 保留设置
+  async getObjectRetention(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    versionId?: string
+  ): Promise<ObjectRetentionInfo | null> {
+    return await invoke<ObjectRetentionInfo | null>('get_object_retention', {
+      config,
+      bucket,
+      key,
+      versionId,
+    })
+  },
+
+  // 设置 Object 保留设置
+  async putObjectRetention(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    retention: ObjectRetentionInfo,
+    versionId?: string,
+    bypassGovernance?: boolean
+  ): Promise<void> {
+    await invoke<void>('put_object_retention', {
+      config,
+      bucket,
+      key,
+      versionId,
+      retention,
+      bypassGovernance,
+    })
+  },
+
+  // 获取 Object 法律保留状态
+  async getObjectLegalHold(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    versionId?: string
+  ): Promise<boolean> {
+    return await invoke<boolean>('get_object_legal_hold', { config, bucket, key, versionId })
+  },
+
+  // 开启或关闭 Object 法律保留
+  async putObjectLegalHold(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    enabled: boolean,
+    versionId?: string
+  ): Promise<void> {
+    await invoke<void>('put_object_legal_hold', { config, bucket, key, versionId, enabled })
+  },
+
+  // 绕过 GOVERNANCE 保留删除 Object（confirmation 必须与 key 一致）
+  async deleteObjectBypassGovernance(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    confirmation: string,
+    versionId?: string
+  ): Promise<void> {
+    await invoke<void>('delete_object_bypass_governance', {
+      config,
+      bucket,
+      key,
+      versionId,
+      confirmation,
+    })
+
+    // 清除相关缓存
+    this._clearListCacheForPrefix(bucket, key)
+  },
+
+  // 获取 Bucket 公共访问阻止设置
+  async getPublicAccessBlock(
+    config: S3Config,
+    bucketName: string
+  ): Promise<PublicAccessBlockSettings> {
+    return await invoke<PublicAccessBlockSettings>('get_public_access_block', {
+      config,
+      bucketName,
+    })
+  },
+
+  // 设置 Bucket 公共访问阻止设置
+  async putPublicAccessBlock(
+    config: S3Config,
+    bucketName: string,
+    settings: PublicAccessBlockSettings
+  ): Promise<void> {
+    await invoke<void>('put_public_access_block', { config, bucketName, settings })
+  },
+
+  // 删除 Bucket 公共访问阻止设置
+  async deletePublicAccessBlock(config: S3Config, bucketName: string): Promise<void> {
+    await invoke<void>('delete_public_access_block', { config, bucketName })
+  },
+
+  // 获取 Bucket 是否可公开访问，无权读取相关配置时返回 null
+  async getBucketPublicStatus(config: S3Config, bucketName: string): Promise<boolean | null> {
+    return await invoke<boolean | null>('get_bucket_public_status', { config, bucketName })
+  },
+
+  // 获取 Bucket ACL
+  async getBucketAcl(config: S3Config, bucketName: string): Promise<AclInfo> {
+    return await invoke<AclInfo>('get_bucket_acl', { config, bucketName })
+  },
+
+  // 设置 Bucket ACL（acl 和 cannedAcl 二选一）
+  async putBucketAcl(
+    config: S3Config,
+    bucketName: string,
+    acl?: AclInfo,
+    cannedAcl?: string
+  ): Promise<void> {
+    await invoke<void>('put_bucket_acl', { config, bucketName, acl, cannedAcl })
+  },
+
+  // 获取 Object ACL
+  async getObjectAcl(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    versionId?: string
+  ): Promise<AclInfo> {
+    return await invoke<AclInfo>('get_object_acl', { config, bucket, key, versionId })
+  },
+
+  // 设置 Object ACL（acl 和 cannedAcl 二选一）
+  async putObjectAcl(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    acl?: AclInfo,
+    cannedAcl?: string,
+    versionId?: string
+  ): Promise<void> {
+    await invoke<void>('put_object_acl', { config, bucket, key, versionId, acl, cannedAcl })
+  },
+
+  // 获取 Bucket 静态网站配置（未配置时返回 null）
+  async getBucketWebsite(config: S3Config, bucketName: string): Promise<WebsiteConfig | null> {
+    return await invoke<WebsiteConfig | null>('get_bucket_website', { config, bucketName })
+  },
+
+  // 设置 Bucket 静态网站配置
+  async putBucketWebsite(
+    config: S3Config,
+    bucketName: string,
+    website: WebsiteConfig
+  ): Promise<void> {
+    await invoke<void>('put_bucket_website', { config, bucketName, website })
+  },
+
+  // 删除 Bucket 静态网站配置
+  async deleteBucketWebsite(config: S3Config, bucketName: string): Promise<void> {
+    await invoke<void>('delete_bucket_website', { config, bucketName })
+  },
+
+  // 计算 Bucket 静态网站访问地址
+  async getWebsiteEndpoint(config: S3Config, bucketName: string): Promise<string> {
+    return await invoke<string>('get_website_endpoint', { config, bucketName })
+  },
+
+  // 统计 Bucket 或前缀的用量（部分结果通过 usage-progress 事件推送，可通过 cancelTask 取消）
+  async getPrefixUsage(
+    config: S3Config,
+    bucket: string,
+    taskId: string,
+    prefix?: string,
+    topN?: number,
+    useCache = true
+  ): Promise<UsageStats> {
+    return await invoke<UsageStats>('get_prefix_usage', {
+      config,
+      bucket,
+      prefix,
+      taskId,
+      topN,
+      useCache,
+    })
+  },
+
+  // 清除 Bucket 的用量统计缓存
+  async clearUsageCache(config: S3Config, bucket: string): Promise<void> {
+    await invoke<void>('clear_usage_cache', { config, bucket })
+  },
+
+  // 导出 Bucket 配置蓝图
+  async exportBucketBlueprint(config: S3Config, bucketName: string): Promise<BucketBlueprint> {
+    return await invoke<BucketBlueprint>('export_bucket_blueprint', { config, bucketName })
+  },
+
+  // 比较两个蓝图的差异
+  async diffBucketBlueprints(
+    left: BucketBlueprint,
+    right: BucketBlueprint
+  ): Promise<BlueprintDifference[]> {
+    return await invoke<BlueprintDifference[]>('diff_bucket_blueprints', { left, right })
+  },
+
+  // 比较蓝图与 Bucket 当前配置的差异（蓝图在左侧）
+  async diffBucketBlueprintLive(
+    config: S3Config,
+    bucketName: string,
+    blueprint: BucketBlueprint
+  ): Promise<BlueprintDifference[]> {
+    return await invoke<BlueprintDifference[]>('diff_bucket_blueprint_live', {
+      config,
+      bucketName,
+      blueprint,
+    })
+  },
+
+  // 将蓝图应用到 Bucket（sections 为空时应用全部配置项）
+  async applyBucketBlueprint(
+    config: S3Config,
+    bucketName: string,
+    blueprint: BucketBlueprint,
+    sections?: BlueprintSection[]
+  ): Promise<BlueprintApplyResult> {
+    return await invoke<BlueprintApplyResult>('apply_bucket_blueprint', {
+      config,
+      bucketName,
+      blueprint,
+      sections,
+    })
+  },
+
+  // 获取 Bucket 事件通知配置
+  async getBucketNotification(config: S3Config, bucketName: string): Promise<NotificationConfig> {
+    return await invoke<NotificationConfig>('get_bucket_notification', { config, bucketName })
+  },
+
+  // 设置 Bucket 事件通知配置（覆盖原有配置）
+  async putBucketNotification(
+    config: S3Config,
+    bucketName: string,
+    notification: NotificationConfig,
+    skipDestinationValidation?: boolean
+  ): Promise<void> {
+    await invoke<void>('put_bucket_notification', {
+      config,
+      bucketName,
+      notification,
+      skipDestinationValidation,
+    })
+  },
+
+  // 删除 Bucket 全部事件通知配置
+  async deleteBucketNotification(config: S3Config, bucketName: string): Promise<void> {
+    await invoke<void>('delete_bucket_notification', { config, bucketName })
+  },
+
+  // 获取支持的通知事件名称（包括 MinIO 扩展事件）
+  async getNotificationEventNames(): Promise<string[]> {
+    return await invoke<string[]>('get_notification_event_names')
+  },
+
+  // 获取 Bucket 复制配置（未配置时返回 null）
+  async getBucketReplication(
+    config: S3Config,
+    bucketName: string
+  ): Promise<ReplicationConfig | null> {
+    return await invoke<ReplicationConfig | null>('get_bucket_replication', { config, bucketName })
+  },
+
+  // 设置 Bucket 复制配置（覆盖原有规则，源和目标都需启用版本控制）
+  async putBucketReplication(
+    config: S3Config,
+    bucketName: string,
+    replication: ReplicationConfig
+  ): Promise<void> {
+    await invoke<void>('put_bucket_replication', { config, bucketName, replication })
+  },
+
+  // 删除 Bucket 复制配置
+  async deleteBucketReplication(config: S3Config, bucketName: string): Promise<void> {
+    await invoke<void>('delete_bucket_replication', { config, bucketName })
+  },
+
+  // 获取 Bucket 服务器访问日志配置（未启用时返回 null）
+  async getBucketLogging(
+    config: S3Config,
+    bucketName: string
+  ): Promise<BucketLoggingConfig | null> {
+    return await invoke<BucketLoggingConfig | null>('get_bucket_logging', { config, bucketName })
+  },
+
+  // 设置 Bucket 服务器访问日志配置（传 null 关闭访问日志）
+  async putBucketLogging(
+    config: S3Config,
+    bucketName: string,
+    logging: BucketLoggingConfig | null
+  ): Promise<void> {
+    await invoke<void>('put_bucket_logging', { config, bucketName, logging })
+  },
+
+  // 分析 Bucket 的服务器访问日志（start/end 为 RFC 3339 时间，进度通过 access-log-progress 事件推送）
+  async analyzeAccessLogs(
+    config: S3Config,
+    bucketName: string,
+    taskId: string,
+    options: {
+      start?: string
+      end?: string
+      logBucket?: string
+      logPrefix?: string
+      topN?: number
+    } = {}
+  ): Promise<AccessLogStats> {
+    return await invoke<AccessLogStats>('analyze_access_logs', {
+      config,
+      bucketName,
+      taskId,
+      ...options,
+    })
+  },
+
+  async getBucketRequestPayment(config: S3Config, bucketName: string): Promise<boolean> {
+    return await invoke<boolean>('get_bucket_request_payment', { config, bucketName })
+  },
+
+  async putBucketRequestPayment(
+    config: S3Config,
+    bucketName: string,
+    requesterPays: boolean
+  ): Promise<void> {
+    return await invoke<void>('put_bucket_request_payment', { config, bucketName, requesterPays })
+  },
+
+  async listIntelligentTieringConfigurations(
+    config: S3Config,
+    bucketName: string
+  ): Promise<IntelligentTieringConfigInfo[]> {
+    return await invoke<IntelligentTieringConfigInfo[]>('list_intelligent_tiering_configurations', {
+      config,
+      bucketName,
+    })
+  },
+
+  async putIntelligentTieringConfiguration(
+    config: S3Config,
+    bucketName: string,
+    configuration: IntelligentTieringConfigInfo
+  ): Promise<void> {
+    return await invoke<void>('put_intelligent_tiering_configuration', {
+      config,
+      bucketName,
+      configuration,
+    })
+  },
+
+  async deleteIntelligentTieringConfiguration(
+    config: S3Config,
+    bucketName: string,
+    id: string
+  ): Promise<void> {
+    return await invoke<void>('delete_intelligent_tiering_configuration', { config, bucketName, id })
+  },
+
+  // 生成预签名下载 URL
+  async presignGetObject(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    expiresInSecs: number,
+    options: {
+      responseContentDisposition?: string
+      responseContentType?: string
+      versionId?: string
+    } = {}
+  ): Promise<PresignedUrl> {
+    return await invoke<PresignedUrl>('presign_get_object', {
+      config,
+      bucket,
+      key,
+      expiresInSecs,
+      ...options,
+    })
+  },
+
+  // 生成预签名上传 URL
+  async presignPutObject(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    expiresInSecs: number,
+    contentType?: string
+  ): Promise<PresignedUrl> {
+    return await invoke<PresignedUrl>('presign_put_object', {
+      config,
+      bucket,
+      key,
+      expiresInSecs,
+      contentType,
+    })
+  },
+
+  // 生成浏览器表单上传的签名 POST Policy
+  async presignPostPolicy(
+    config: S3Config,
+    bucket: string,
+    options: PostPolicyOptions
+  ): Promise<PresignedPost> {
+    return await invoke<PresignedPost>('presign_post_policy', { config, bucket, options })
+  },
+
+  // 生成分享链接并记录到本地
+  async createShareLink(
+    config: S3Config,
+    bucket: string,
+    key: string,
+    expiresInSecs: number,
+    options: {
+      versionId?: string
+      responseContentDisposition?: string
+      note?: string
+      createdBy?: string
+    } = {}
+  ): Promise<ShareRecord> {
+    return await invoke<ShareRecord>('create_share_link', {
+      config,
+      password: securityService.getMasterPassword(),
+      bucket,
+      key,
+      expiresInSecs,
+      ...options,
+    })
+  },
+
+  // 列出分享记录，includeInactive 为 true 时包含已过期和已撤销的记录
+  async listShareLinks(configId?: string, includeInactive = false): Promise<ShareRecord[]> {
+    return await invoke<ShareRecord[]>('list_share_links', { configId, includeInactive })
+  },
+
+  // 获取仍然有效的分享链接（记录中的 URL 用主密码加密保存）
+  async getShareLinkUrl(id: string): Promise<string> {
+    return await invoke<string>('get_share_link_url', {
+      id,
+      password: securityService.getMasterPassword(),
+    })
+  },
+
+  // 删除本地分享记录
+  async deleteShareLink(id: string): Promise<void> {
+    return await invoke<void>('delete_share_link', { id })
+  },
+
+  // 撤销分享链接：rotate_key 移动对象到 newKey，policy 通过 Bucket 策略拒绝预签名访问
+  async revokeShareLink(
+    config: S3Config,
+    share: ShareRecord,
+    method: 'rotate_key' | 'policy',
+    newKey?: string
+  ): Promise<void> {
+    await invoke<void>('revoke_share_link', { config, id: share.id, method, newKey })
+
+    if (method === 'rotate_key' && newKey) {
+      this._clearListCacheForPrefix(share.bucket, share.key)
+      this._clearListCacheForPrefix(share.bucket, newKey)
+    }
   },
 
   // 辅助方法：清除特定前缀的缓存
@@ -29,6 +29,9 @@ async function encryptConfig(config: S3Config): Promise<S3Config> {
     ...config,
     access_key_id: await securityService.encrypt(config.access_key_id, masterPassword),
     secret_access_key: await securityService.encrypt(config.secret_access_key, masterPassword),
+    sse_customer_key: config.sse_customer_key
+      ? await securityService.encrypt(config.sse_customer_key, masterPassword)
+      : undefined,
     _encrypted: true, // 标记为已加密
   }
 
@@ -49,6 +52,9 @@ async function decryptConfig(config: S3Config): Promise<S3Config> {
       ...config,
       access_key_id: await securityService.decrypt(config.access_key_id, masterPassword),
       secret_access_key: await securityService.decrypt(config.secret_access_key, masterPassword),
+      sse_customer_key: config.sse_customer_key
+        ? await securityService.decrypt(config.sse_customer_key, masterPassword)
+        : undefined,
       _encrypted: undefined, // 移除加密标记
     }
     return decrypted
@@ -8,6 +8,16 @@ export interface S3Config {
   endpoint?: string
   session_token?: string
   bucket?: string
+  // 保存的 SSE-C 密钥（Base64 编码的 256 位密钥），读写 SSE-C 对象时需作为 sseCustomerKey 显式传入
+  sse_customer_key?: string
+  // 对所有 Bucket 发送 x-amz-request-payer: requester
+  requester_pays?: boolean
+  // 仅对这些请求者付费的 Bucket 发送 x-amz-request-payer
+  requester_pays_buckets?: string[]
+  // 使用 path-style 地址（endpoint/bucket/key），默认关闭
+  force_path_style?: boolean
+  // 临时凭证（session_token）的过期时间，RFC 3339 格式，分享链接会在此时提前失效
+  session_expires_at?: string
 }
 
 // Bucket 信息
@@ -15,6 +25,22 @@ export interface BucketInfo {
   name: string
   creation_date: string
   region?: string
+  is_public?: boolean
+  // Enabled、Suspended 或 Disabled（从未启用），未获取时为空
+  versioning?: string
+  tags?: TagInfo[]
+}
+
+// 分页列出 Buckets 结果
+export interface ListBucketsResult {
+  buckets: BucketInfo[]
+  continuation_token?: string
+}
+
+// 单个 Bucket 补充信息完成事件（bucket-enriched）
+export interface BucketEnriched {
+  task_id: string
+  bucket: BucketInfo
 }
 
 // Object 元数据
@@ -25,7 +51,13 @@ export interface ObjectMetadata {
   etag: string
   storage_class: string
   content_type?: string
+  // 公共前缀或以 / 结尾的空目录标记对象（有内容的以 / 结尾对象仍为文件）
   is_folder: boolean
+  tag_count?: number
+  server_side_encryption?: string
+  sse_kms_key_id?: string
+  // PENDING、COMPLETED、FAILED、REPLICA 等，仅 headObject 返回
+  replication_status?: string
 }
 
 // 列出 Objects 结果
@@ -51,3 +83,443 @@ export interface DownloadProgress {
   total_bytes: number
   percentage: number
 }
+
+// 标签
+export interface TagInfo {
+  key: string
+  value: string
+}
+
+// 批量操作进度
+export interface BatchProgress {
+  task_id: string
+  processed: number
+  failed: number
+  current_key?: string
+}
+
+// 批量操作中失败的对象
+export interface BatchFailure {
+  key: string
+  code?: string
+  message: string
+}
+
+// 批量操作结果
+export interface BatchResult {
+  task_id: string
+  succeeded: number
+  failures: BatchFailure[]
+  cancelled: boolean
+}
+
+// 服务端加密选项（上传和复制）
+export interface SseOptions {
+  algorithm: 'AES256' | 'aws:kms' | 'aws:kms:dsse'
+  kms_key_id?: string
+  bucket_key_enabled?: boolean
+}
+
+// Bucket 默认加密配置
+export interface BucketEncryption {
+  algorithm: 'AES256' | 'aws:kms' | 'aws:kms:dsse'
+  kms_key_id?: string
+  bucket_key_enabled: boolean
+}
+
+// Bucket Object Lock 配置
+export interface ObjectLockConfig {
+  enabled: boolean
+  mode?: 'GOVERNANCE' | 'COMPLIANCE'
+  days?: number
+  years?: number
+}
+
+// Object 保留设置
+export interface ObjectRetentionInfo {
+  mode: 'GOVERNANCE' | 'COMPLIANCE'
+  // RFC 3339 格式
+  retain_until_date: string
+}
+
+// 公共访问阻止设置
+export interface PublicAccessBlockSettings {
+  block_public_acls: boolean
+  ignore_public_acls: boolean
+  block_public_policy: boolean
+  restrict_public_buckets: boolean
+}
+
+// ACL 授权
+export interface GrantInfo {
+  grantee_type: 'CanonicalUser' | 'Group' | 'AmazonCustomerByEmail'
+  grantee_id?: string
+  grantee_uri?: string
+  grantee_email?: string
+  grantee_display_name?: string
+  permission: 'FULL_CONTROL' | 'READ' | 'WRITE' | 'READ_ACP' | 'WRITE_ACP'
+}
+
+// ACL 信息
+export interface AclInfo {
+  owner_id?: string
+  owner_display_name?: string
+  grants: GrantInfo[]
+  is_public: boolean
+}
+
+// 静态网站托管配置
+export interface WebsiteConfig {
+  index_document?: string
+  error_document?: string
+  // 设置后所有请求都重定向到指定主机，不能与其他选项同时使用
+  redirect_all_requests_to?: {
+    host_name: string
+    protocol?: 'http' | 'https'
+  }
+  routing_rules: RoutingRuleInfo[]
+}
+
+// 网站路由规则
+export interface RoutingRuleInfo {
+  key_prefix_equals?: string
+  http_error_code_returned_equals?: string
+  redirect: {
+    host_name?: string
+    http_redirect_code?: string
+    protocol?: 'http' | 'https'
+    replace_key_prefix_with?: string
+    replace_key_with?: string
+  }
+}
+
+// 用量汇总
+export interface UsageTotals {
+  size: number
+  count: number
+}
+
+// Bucket 或前缀的用量统计
+export interface UsageStats {
+  task_id: string
+  bucket: string
+  prefix: string
+  total_size: number
+  object_count: number
+  by_storage_class: Record<string, UsageTotals>
+  // 按直接子前缀汇总
+  by_prefix: Record<string, UsageTotals>
+  largest_objects: Array<{
+    key: string
+    size: number
+    storage_class: string
+  }>
+  // 遍历完成为 true，进度事件和取消时的部分结果为 false
+  completed: boolean
+  computed_at: string
+}
+
+// 创建 Bucket 的完整选项（按顺序应用，任一步失败会删除已创建的 Bucket）
+export interface CreateBucketOptions {
+  region?: string
+  object_lock_enabled?: boolean
+  // 启用 Object Lock 时必须为 true
+  versioning_enabled?: boolean
+  encryption?: BucketEncryption
+  tags?: TagInfo[]
+  public_access_block?: PublicAccessBlockSettings
+  // 公共 ACL（public-read 等）需要同时设置 block_public_acls 为 false 的 public_access_block
+  canned_acl?: string
+}
+
+// 生命周期转换（非当前版本转换只使用 days）
+export interface LifecycleTransitionInfo {
+  days?: number
+  // RFC 3339 格式
+  date?: string
+  storage_class: string
+}
+
+// 生命周期规则
+export interface LifecycleRuleInfo {
+  id?: string
+  enabled: boolean
+  prefix?: string
+  tags?: TagInfo[]
+  object_size_greater_than?: number
+  object_size_less_than?: number
+  expiration_days?: number
+  expiration_date?: string
+  expired_object_delete_marker?: boolean
+  transitions?: LifecycleTransitionInfo[]
+  noncurrent_expiration_days?: number
+  newer_noncurrent_versions?: number
+  noncurrent_transitions?: LifecycleTransitionInfo[]
+  abort_incomplete_multipart_days?: number
+}
+
+// CORS 规则
+export interface CorsRuleInfo {
+  id?: string
+  allowed_methods: string[]
+  allowed_origins: string[]
+  allowed_headers?: string[]
+  expose_headers?: string[]
+  max_age_seconds?: number
+}
+
+// Bucket 配置蓝图（未配置的项在应用时会从目标 Bucket 删除）
+export interface BucketBlueprint {
+  version: number
+  source_bucket: string
+  exported_at: string
+  // Enabled 或 Suspended，从未启用时为空
+  versioning?: string
+  lifecycle: LifecycleRuleInfo[]
+  policy?: Record<string, unknown>
+  cors: CorsRuleInfo[]
+  encryption?: BucketEncryption
+  tags: TagInfo[]
+  website?: WebsiteConfig
+  public_access_block?: PublicAccessBlockSettings
+  // 导出时读取失败的配置项，key 为配置项名称；比较和应用时跳过这些项
+  unreadable?: BatchFailure[]
+}
+
+// 蓝图配置项名称
+export type BlueprintSection =
+  | 'versioning'
+  | 'public_access_block'
+  | 'policy'
+  | 'encryption'
+  | 'lifecycle'
+  | 'cors'
+  | 'tags'
+  | 'website'
+
+// 蓝图差异
+export interface BlueprintDifference {
+  section: BlueprintSection
+  left: unknown
+  right: unknown
+}
+
+// 应用蓝图的结果（失败项的 key 为配置项名称）
+export interface BlueprintApplyResult {
+  applied: BlueprintSection[]
+  failures: BatchFailure[]
+}
+
+// Bucket 事件通知规则（MinIO 的 webhook 等目标使用 queue 类型和 arn:minio:sqs:... ARN）
+export interface NotificationRuleInfo {
+  id?: string
+  target_type: 'queue' | 'topic' | 'lambda'
+  target_arn: string
+  events: string[]
+  prefix?: string
+  suffix?: string
+}
+
+// Bucket 事件通知配置
+export interface NotificationConfig {
+  rules: NotificationRuleInfo[]
+  event_bridge_enabled: boolean
+}
+
+// 复制规则
+export interface ReplicationRuleInfo {
+  id?: string
+  // 未指定时按规则顺序编号
+  priority?: number
+  enabled: boolean
+  prefix?: string
+  tags?: TagInfo[]
+  // 目标 Bucket 名称或 ARN（MinIO 使用 arn:minio:replication:... 形式）
+  destination_bucket: string
+  destination_account?: string
+  storage_class?: string
+  delete_marker_replication?: boolean
+}
+
+// Bucket 复制配置
+export interface ReplicationConfig {
+  // 复制使用的 IAM 角色 ARN，MinIO 可为空
+  role: string
+  rules: ReplicationRuleInfo[]
+}
+
+// Bucket 服务器访问日志配置
+export interface BucketLoggingConfig {
+  target_bucket: string
+  target_prefix: string
+  // 按 账户/区域/Bucket/年/月/日 分区存放日志对象
+  partitioned_prefix?: boolean
+}
+
+// 访问日志汇总
+export interface AccessLogTotals {
+  requests: number
+  bytes_sent: number
+}
+
+// 按 Object key 或请求者汇总的访问日志
+export interface AccessLogCount extends AccessLogTotals {
+  name: string
+}
+
+// 访问日志分析结果
+export interface AccessLogStats {
+  task_id: string
+  bucket: string
+  log_bucket: string
+  log_prefix: string
+  start?: string
+  end?: string
+  log_objects_scanned: number
+  // 读取失败的日志对象，不计入统计
+  failed_objects: BatchFailure[]
+  total_requests: number
+  total_bytes_sent: number
+  // 无法解析的日志行数
+  parse_errors: number
+  // 按操作（如 REST.GET.OBJECT）汇总
+  by_operation: Record<string, AccessLogTotals>
+  // 按 HTTP 状态码汇总
+  by_status: Record<string, AccessLogTotals>
+  top_keys: AccessLogCount[]
+  top_requesters: AccessLogCount[]
+  // 遍历完成为 true，进度事件和取消时的部分结果为 false
+  completed: boolean
+}
+
+// 智能分层归档配置
+export interface IntelligentTieringConfigInfo {
+  id: string
+  enabled: boolean
+  prefix?: string
+  tags: TagInfo[]
+  // 连续未访问多少天后进入 Archive Access 层（90-730）
+  archive_access_days?: number
+  // 连续未访问多少天后进入 Deep Archive Access 层（180-730）
+  deep_archive_access_days?: number
+}
+
+// 可修改的对象元数据
+export interface ObjectMetadataUpdate {
+  content_type?: string
+  cache_control?: string
+  content_disposition?: string
+  content_encoding?: string
+  content_language?: string
+  // RFC 3339 或 HTTP 日期格式
+  expires?: string
+  // 不含 x-amz-meta- 前缀的用户元数据，非 ASCII 的值在请求中按 RFC 2047 编码
+  metadata: Record<string, string>
+}
+
+// head_object 返回的完整对象信息，时间均为 RFC 3339 格式
+export interface ObjectDetails {
+  key: string
+  version_id?: string
+  size: number
+  etag?: string
+  last_modified?: string
+  storage_class: string
+  delete_marker: boolean
+  content_type?: string
+  cache_control?: string
+  content_disposition?: string
+  content_encoding?: string
+  content_language?: string
+  expires?: string
+  website_redirect_location?: string
+  // RFC 2047 编码的值已解码
+  metadata: Record<string, string>
+  tag_count?: number
+  server_side_encryption?: string
+  sse_kms_key_id?: string
+  bucket_key_enabled?: boolean
+  sse_customer_algorithm?: string
+  // 未发起恢复时为空
+  restore_in_progress?: boolean
+  restore_expiry_date?: string
+  archive_status?: string
+  expiration_date?: string
+  expiration_rule_id?: string
+  object_lock_mode?: string
+  object_lock_retain_until_date?: string
+  object_lock_legal_hold?: boolean
+  replication_status?: string
+  checksum_crc32?: string
+  checksum_crc32c?: string
+  checksum_crc64nvme?: string
+  checksum_sha1?: string
+  checksum_sha256?: string
+  checksum_type?: string
+  parts_count?: number
+}
+
+// 预签名 URL
+export interface PresignedUrl {
+  url: string
+  method: string
+  // RFC 3339 过期时间
+  expires_at: string
+  // 使用 URL 时必须携带的请求头
+  headers: Record<string, string>
+}
+
+// 浏览器表单上传（POST Policy）的限制条件，key 与 key_prefix 二选一
+export interface PostPolicyOptions {
+  key?: string
+  key_prefix?: string
+  expires_in_secs: number
+  content_length_min?: number
+  content_length_max?: number
+  // Content-Type 必须以此开头，例如 image/
+  content_type_prefix?: string
+  acl?: string
+  success_action_redirect?: string
+}
+
+// 表单字段
+export interface PostFormField {
+  name: string
+  value: string
+}
+
+// 签名后的 POST 表单
+export interface PresignedPost {
+  url: string
+  // 按顺序放在 file 字段之前
+  fields: PostFormField[]
+  expires_at: string
+  html: string
+  curl: string
+}
+
+// 本地记录的分享链接
+export interface ShareRecord {
+  id: string
+  config_id: string
+  connection_name: string
+  bucket: string
+  key: string
+  version_id?: string
+  // 明文 URL 只在创建时返回，之后通过 getShareLinkUrl 解密获取
+  url?: string
+  // 用主密码加密后的 URL
+  encrypted_url?: string
+  created_by: string
+  created_at: string
+  expires_at: string
+  // 使用临时凭证签名时凭证的过期时间
+  credentials_expire_at?: string
+  note?: string
+  revoked_at?: string
+  // rotate_key 或 policy
+  revocation?: string
+  // 通过更换 key 撤销时对象的新 key
+  rotated_to?: string
+  status: 'active' | 'expired' | 'revoked'
+}